@@ -0,0 +1,239 @@
+//! Const Base64 (RFC 4648) alphabets and decoding.
+
+use core::fmt::Display;
+
+/// Base64 alphabet and padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base64Style {
+	/// Standard alphabet (`+/`) with `=` padding.
+	Standard,
+	/// Standard alphabet (`+/`) without padding.
+	StandardNoPad,
+	/// URL and filename safe alphabet (`-_`) with `=` padding.
+	UrlSafe,
+	/// URL and filename safe alphabet (`-_`) without padding.
+	UrlSafeNoPad,
+}
+
+impl Base64Style {
+	/// The 64 characters of the alphabet.
+	pub const fn alphabet(self) -> &'static [u8; 64] {
+		match self {
+			Self::Standard | Self::StandardNoPad => {
+				b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+			}
+			Self::UrlSafe | Self::UrlSafeNoPad => {
+				b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+			}
+		}
+	}
+
+	/// `true` if the encoder appends `=` padding.
+	pub const fn is_padded(self) -> bool {
+		matches!(self, Self::Standard | Self::UrlSafe)
+	}
+
+	/// Number of characters produced when encoding `data_len` bytes.
+	pub const fn encoded_len(self, data_len: usize) -> usize {
+		match self.is_padded() {
+			true => data_len.div_ceil(3) * 4,
+			false => (data_len * 4).div_ceil(3),
+		}
+	}
+
+	/// Value of an alphabet character.
+	const fn decode_char(self, c: u8) -> Option<u8> {
+		match c {
+			b'A'..=b'Z' => Some(c - b'A'),
+			b'a'..=b'z' => Some(c - b'a' + 26),
+			b'0'..=b'9' => Some(c - b'0' + 52),
+			_ => {
+				let alphabet = self.alphabet();
+				if c == alphabet[62] {
+					Some(62)
+				} else if c == alphabet[63] {
+					Some(63)
+				} else {
+					None
+				}
+			}
+		}
+	}
+}
+
+/// Error type returned by [`decode_base64`] and [`decoded_len`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base64DecodeError {
+	/// A character is not part of the alphabet.
+	InvalidChar,
+	/// The number of characters is not valid Base64, or the padding is wrong.
+	InvalidLength,
+	/// The unused bits of the last character are not zero.
+	NonCanonical,
+	/// The output array length does not match the number of encoded bytes.
+	LengthMismatch,
+}
+
+impl Base64DecodeError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::InvalidChar => "invalid Base64: unexpected character",
+			Self::InvalidLength => "invalid Base64: invalid length or padding",
+			Self::NonCanonical => "invalid Base64: trailing bits are not zero",
+			Self::LengthMismatch => "invalid Base64: output length does not match",
+		}
+	}
+}
+
+impl Display for Base64DecodeError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for Base64DecodeError {}
+
+/// Walks `s`, writing decoded bytes into `out` (if any), and returns their number.
+const fn decode_into(
+	s: &str,
+	style: Base64Style,
+	mut out: Option<&mut [u8]>,
+) -> Result<usize, Base64DecodeError> {
+	let s = s.as_bytes();
+	let mut chars = 0;
+	let mut padding = 0;
+	let mut bits = 0u32;
+	let mut len = 0;
+	let mut i = 0;
+	while i < s.len() {
+		let c = s[i];
+		i += 1;
+		if c.is_ascii_whitespace() {
+			continue;
+		}
+		if c == b'=' {
+			padding += 1;
+			continue;
+		}
+		if padding != 0 {
+			return Err(Base64DecodeError::InvalidLength); // data after padding
+		}
+
+		let value = match style.decode_char(c) {
+			Some(a) => a,
+			None => return Err(Base64DecodeError::InvalidChar),
+		};
+		bits = bits << 6 | value as u32;
+		chars += 1;
+		if chars % 4 == 0 {
+			let bytes = bits.to_be_bytes();
+			if let Some(out) = &mut out {
+				if len + 3 > out.len() {
+					return Err(Base64DecodeError::LengthMismatch);
+				}
+				out[len] = bytes[1];
+				out[len + 1] = bytes[2];
+				out[len + 2] = bytes[3];
+			}
+			len += 3;
+			bits = 0;
+		}
+	}
+
+	// the last group has 2 or 3 characters: 1 or 2 bytes
+	let rest = match chars % 4 {
+		0 => 0,
+		2 => 1,
+		3 => 2,
+		_ => return Err(Base64DecodeError::InvalidLength),
+	};
+	if padding != 0 && (padding > 2 || (chars + padding) % 4 != 0) {
+		return Err(Base64DecodeError::InvalidLength);
+	}
+	let unused_bits = (chars % 4) * 6 - rest * 8;
+	if bits & ((1 << unused_bits) - 1) != 0 {
+		return Err(Base64DecodeError::NonCanonical);
+	}
+
+	if rest != 0 {
+		let bytes = (bits >> unused_bits).to_be_bytes();
+		if let Some(out) = &mut out {
+			if len + rest > out.len() {
+				return Err(Base64DecodeError::LengthMismatch);
+			}
+			out[len] = bytes[4 - rest];
+			if rest == 2 {
+				out[len + 1] = bytes[3];
+			}
+		}
+		len += rest;
+	}
+
+	Ok(len)
+}
+
+/// Number of bytes encoded in `s`, validating it.
+///
+/// ASCII whitespace is skipped and `=` padding is optional, but must be complete
+/// when present.
+pub const fn decoded_len(s: &str, style: Base64Style) -> Result<usize, Base64DecodeError> {
+	decode_into(s, style, None)
+}
+
+/// Decodes `s` into exactly `N` bytes.
+///
+/// Accepts the same syntax as [`decoded_len`].
+pub const fn decode_base64<const N: usize>(
+	s: &str,
+	style: Base64Style,
+) -> Result<[u8; N], Base64DecodeError> {
+	let mut result = [0u8; N];
+	match decode_into(s, style, Some(&mut result)) {
+		Ok(len) if len == N => Ok(result),
+		Ok(_) => Err(Base64DecodeError::LengthMismatch),
+		Err(e) => Err(e),
+	}
+}
+
+/// Compile-time Base64 decoding into an exact-size `[u8; N]` array.
+///
+/// The style defaults to [`Standard`](crate::base64::Base64Style::Standard); only the
+/// alphabet of the style matters, padding is optional. ASCII whitespace is skipped;
+/// invalid input is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::base64_decode;
+///
+/// const TOKEN: [u8; 5] = base64_decode!("aGVsbG8=");
+/// assert_eq!(&TOKEN, b"hello");
+///
+/// const ID: [u8; 4] = base64_decode!("-_-_-w", UrlSafe);
+/// assert_eq!(ID, [0xfb, 0xff, 0xbf, 0xfb]);
+/// ```
+///
+/// ```rust,compile_fail
+/// const TOKEN: [u8; 5] = cluConstData::base64_decode!("aGVsbG8*");
+/// ```
+#[macro_export]
+macro_rules! base64_decode {
+	[$s: expr $(,)?] => {
+		$crate::base64_decode!($s, Standard)
+	};
+	[$s: expr, $style: ident $(,)?] => {{
+		const _STR: &str = $s;
+		const _STYLE: $crate::base64::Base64Style = $crate::base64::Base64Style::$style;
+		const _LEN: usize = match $crate::base64::decoded_len(_STR, _STYLE) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+		const _HIDDEN: [u8; _LEN] = match $crate::base64::decode_base64(_STR, _STYLE) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		_HIDDEN
+	}};
+}
@@ -0,0 +1,213 @@
+//! Conversion of a buffer into terminated or length-prefixed arrays.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use core::fmt::Display;
+
+/// Width and byte order of a length prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LenPrefix {
+	/// One byte (Pascal string).
+	U8,
+	/// Two bytes, little-endian.
+	U16Le,
+	/// Two bytes, big-endian.
+	U16Be,
+	/// Four bytes, little-endian.
+	U32Le,
+	/// Four bytes, big-endian.
+	U32Be,
+}
+
+impl LenPrefix {
+	/// Number of bytes occupied by the prefix.
+	#[inline]
+	pub const fn width(self) -> usize {
+		match self {
+			Self::U8 => 1,
+			Self::U16Le | Self::U16Be => 2,
+			Self::U32Le | Self::U32Be => 4,
+		}
+	}
+
+	/// Largest length representable by the prefix.
+	#[inline]
+	pub const fn max_len(self) -> usize {
+		match self {
+			Self::U8 => u8::MAX as usize,
+			Self::U16Le | Self::U16Be => u16::MAX as usize,
+			Self::U32Le | Self::U32Be => {
+				if u32::BITS >= usize::BITS {
+					usize::MAX
+				} else {
+					u32::MAX as usize
+				}
+			}
+		}
+	}
+
+	/// Encodes `len` into the first `width()` bytes of `out`.
+	///
+	/// `len` must not exceed `max_len()`.
+	pub(crate) const fn encode(self, len: usize, out: &mut [u8]) {
+		let mut bytes = [0u8; 4];
+		match self {
+			Self::U8 => bytes[0] = len as u8,
+			Self::U16Le => {
+				let [a, b] = (len as u16).to_le_bytes();
+				bytes = [a, b, 0, 0];
+			}
+			Self::U16Be => {
+				let [a, b] = (len as u16).to_be_bytes();
+				bytes = [a, b, 0, 0];
+			}
+			Self::U32Le => bytes = (len as u32).to_le_bytes(),
+			Self::U32Be => bytes = (len as u32).to_be_bytes(),
+		}
+
+		let mut i = 0;
+		while i < self.width() {
+			out[i] = bytes[i];
+			i += 1;
+		}
+	}
+}
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Converts this buffer into a NUL-terminated `[u8; CAP]` array,
+	/// filling remaining capacity with NUL bytes and also returning its original length.
+	///
+	/// Panics if there is no room for the trailing NUL or the data contains a NUL byte.
+	#[track_caller]
+	pub const fn into_array_nul_terminated(self) -> (usize, [u8; CAP]) {
+		match self.try_into_array_nul_terminated() {
+			Ok(a) => a,
+			Err(e) => e.cold_panic(),
+		}
+	}
+
+	/// Converts this buffer into a NUL-terminated `[u8; CAP]` array,
+	/// filling remaining capacity with NUL bytes and also returning its original length.
+	pub const fn try_into_array_nul_terminated(self) -> Result<(usize, [u8; CAP]), IntoArrayError> {
+		let data = self.as_bytes();
+		if data.len() >= CAP {
+			return Err(IntoArrayError::Overflow);
+		}
+
+		let mut result = [0u8; CAP];
+		let mut i = 0;
+		while i < data.len() {
+			if data[i] == 0 {
+				return Err(IntoArrayError::InteriorNul(i));
+			}
+			result[i] = data[i];
+			i += 1;
+		}
+
+		Ok((data.len(), result))
+	}
+
+	/// Converts this buffer into a `[u8; N]` array starting with a length prefix,
+	/// filling remaining space with zero bytes and also returning the number of bytes used
+	/// (prefix included).
+	///
+	/// Panics if the array is too small or the length does not fit into the prefix.
+	#[track_caller]
+	pub const fn into_array_with_len_prefix<const N: usize>(
+		self,
+		prefix: LenPrefix,
+	) -> (usize, [u8; N]) {
+		match self.try_into_array_with_len_prefix(prefix) {
+			Ok(a) => a,
+			Err(e) => e.cold_panic(),
+		}
+	}
+
+	/// Converts this buffer into a `[u8; N]` array starting with a length prefix,
+	/// filling remaining space with zero bytes and also returning the number of bytes used
+	/// (prefix included).
+	pub const fn try_into_array_with_len_prefix<const N: usize>(
+		self,
+		prefix: LenPrefix,
+	) -> Result<(usize, [u8; N]), IntoArrayError> {
+		let data = self.as_bytes();
+		if data.len() > prefix.max_len() {
+			return Err(IntoArrayError::LenTooLarge);
+		}
+		let width = prefix.width();
+		if width + data.len() > N {
+			return Err(IntoArrayError::Overflow);
+		}
+
+		let mut result = [0u8; N];
+		prefix.encode(data.len(), &mut result);
+
+		let mut i = 0;
+		while i < data.len() {
+			result[width + i] = data[i];
+			i += 1;
+		}
+
+		Ok((width + data.len(), result))
+	}
+
+	/// Copies the written bytes into an array of exactly `N` bytes.
+	///
+	/// Panics if `N` differs from `len()`.
+	///
+	/// Only for internal use in macros!
+	#[doc(hidden)]
+	#[track_caller]
+	pub const fn __into_exact_array<const N: usize>(self) -> [u8; N] {
+		let data = self.as_bytes();
+		if data.len() != N {
+			panic!("ConstByteBuf: written length does not match the exact array length");
+		}
+
+		let mut result = [0u8; N];
+		let mut i = 0;
+		while i < N {
+			result[i] = data[i];
+			i += 1;
+		}
+
+		result
+	}
+}
+
+/// Error type returned by the `into_array_*` conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntoArrayError {
+	/// The target array is too small for the data (and its terminator or prefix).
+	Overflow,
+	/// The data contains a NUL byte at the given position.
+	InteriorNul(usize),
+	/// The data length does not fit into the length prefix.
+	LenTooLarge,
+}
+
+impl IntoArrayError {
+	/// Panics with a message describing the error.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_panic(self) -> ! {
+		match self {
+			Self::Overflow => panic!("ConstByteBuf: target array is too small"),
+			Self::InteriorNul(_) => panic!("ConstByteBuf: data contains an interior NUL byte"),
+			Self::LenTooLarge => panic!("ConstByteBuf: length does not fit into the prefix"),
+		}
+	}
+}
+
+impl Display for IntoArrayError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Overflow => f.write_str("target array is too small"),
+			Self::InteriorNul(pos) => write!(f, "interior NUL byte at position {pos}"),
+			Self::LenTooLarge => f.write_str("length does not fit into the prefix"),
+		}
+	}
+}
+
+impl core::error::Error for IntoArrayError {}
@@ -0,0 +1,109 @@
+//! Const Base64 (RFC 4648) encoding.
+
+use crate::base64::Base64Style;
+use crate::buf::ConstByteBuf;
+use crate::buf::StackOverflow;
+use crate::buf::Utf8SafeBuf;
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Appends `data` encoded as Base64 with the given alphabet and padding.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::base64::Base64Style;
+	/// use cluConstData::buf::ConstStrBuf;
+	///
+	/// const STYLE: Base64Style = Base64Style::UrlSafeNoPad;
+	/// const TOKEN: ConstStrBuf<{ STYLE.encoded_len(5) }> = {
+	///	let mut buf = ConstStrBuf::new();
+	///	buf.push_base64(b"hello", STYLE);
+	///	buf
+	/// };
+	/// assert_eq!(TOKEN, "aGVsbG8");
+	/// ```
+	#[track_caller]
+	pub const fn push_base64(&mut self, data: &[u8], style: Base64Style) -> usize {
+		match self.try_push_base64(data, style) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `data` encoded as Base64 with the given alphabet and padding.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_base64(
+		&mut self,
+		data: &[u8],
+		style: Base64Style,
+	) -> Result<usize, StackOverflow> {
+		let len = style.encoded_len(data.len());
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let alphabet = style.alphabet();
+		let mut i = 0;
+		while i < data.len() {
+			let rest = data.len() - i;
+			let b0 = data[i] as u32;
+			let b1 = if rest > 1 { data[i + 1] as u32 } else { 0 };
+			let b2 = if rest > 2 { data[i + 2] as u32 } else { 0 };
+			let bits = b0 << 16 | b1 << 8 | b2;
+
+			let group = [
+				alphabet[(bits >> 18) as usize & 63],
+				alphabet[(bits >> 12) as usize & 63],
+				alphabet[(bits >> 6) as usize & 63],
+				alphabet[bits as usize & 63],
+			];
+			// 1 byte => 2 chars, 2 bytes => 3 chars, 3 bytes => 4 chars
+			let chars = if rest >= 3 { 4 } else { rest + 1 };
+			let (group, _) = group.split_at(chars);
+			let _ = self.__try_write_bytes_unchecked(group);
+			if style.is_padded() && chars < 4 {
+				let (padding, _) = b"==".split_at(4 - chars);
+				let _ = self.__try_write_bytes_unchecked(padding);
+			}
+
+			i += 3;
+		}
+
+		Ok(len)
+	}
+}
+
+/// Compile-time Base64 representation of bytes as `&'static str`.
+///
+/// Takes the bytes (`&[u8]` or `[u8; N]`) and optionally the
+/// [`Base64Style`](crate::base64::Base64Style) (`Standard` by default).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::base64_str;
+///
+/// const HELLO: &[u8] = b"hello";
+///
+/// assert_eq!(base64_str!(HELLO), "aGVsbG8=");
+/// assert_eq!(base64_str!(HELLO, UrlSafeNoPad), "aGVsbG8");
+/// assert_eq!(base64_str!([0xfb, 0xff], UrlSafe), "-_8=");
+/// ```
+#[macro_export]
+macro_rules! base64_str {
+	[$bytes: expr $(,)?] => {
+		$crate::base64_str!($bytes, Standard)
+	};
+	[$bytes: expr, $style: ident $(,)?] => {{
+		const _BYTES: &[u8] = &$bytes;
+		const _STYLE: $crate::base64::Base64Style = $crate::base64::Base64Style::$style;
+
+		$crate::__buf_str!(_STYLE.encoded_len(_BYTES.len()), {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_base64(_BYTES, _STYLE);
+			buf
+		})
+	}};
+}
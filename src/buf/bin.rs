@@ -0,0 +1,172 @@
+//! Binary serialization primitives for raw byte buffers.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::DefBuf;
+use crate::buf::StackOverflow;
+use crate::buf::size::ConstByteBufLeb128Size;
+
+/// Generates fixed-width `push_*`/`try_push_*` method pairs.
+///
+/// Each entry maps a value type to its `to_le_bytes`/`to_be_bytes` encoding.
+macro_rules! impl_push_bin {
+	[
+		$(
+			$(#[$meta:meta])*
+			$name:ident, $try_name:ident: $ty:ty => $to_bytes:ident;
+		)*
+	] => {
+		$(
+			$(#[$meta])*
+			///
+			/// Panics on overflow.
+			#[track_caller]
+			#[inline]
+			pub const fn $name(&mut self, value: $ty) -> usize {
+				self.write_bytes(&value.$to_bytes())
+			}
+
+			$(#[$meta])*
+			#[inline]
+			pub const fn $try_name(&mut self, value: $ty) -> Result<usize, StackOverflow> {
+				self.try_write_bytes(&value.$to_bytes())
+			}
+		)*
+	};
+}
+
+impl<const CAP: usize> ConstByteBuf<CAP, DefBuf> {
+	impl_push_bin! {
+		/// Appends `u8`.
+		push_u8, try_push_u8: u8 => to_le_bytes;
+		/// Appends `i8`.
+		push_i8, try_push_i8: i8 => to_le_bytes;
+
+		/// Appends `u16` in little-endian byte order.
+		push_u16_le, try_push_u16_le: u16 => to_le_bytes;
+		/// Appends `u16` in big-endian byte order.
+		push_u16_be, try_push_u16_be: u16 => to_be_bytes;
+		/// Appends `u32` in little-endian byte order.
+		push_u32_le, try_push_u32_le: u32 => to_le_bytes;
+		/// Appends `u32` in big-endian byte order.
+		push_u32_be, try_push_u32_be: u32 => to_be_bytes;
+		/// Appends `u64` in little-endian byte order.
+		push_u64_le, try_push_u64_le: u64 => to_le_bytes;
+		/// Appends `u64` in big-endian byte order.
+		push_u64_be, try_push_u64_be: u64 => to_be_bytes;
+		/// Appends `u128` in little-endian byte order.
+		push_u128_le, try_push_u128_le: u128 => to_le_bytes;
+		/// Appends `u128` in big-endian byte order.
+		push_u128_be, try_push_u128_be: u128 => to_be_bytes;
+
+		/// Appends `i16` in little-endian byte order.
+		push_i16_le, try_push_i16_le: i16 => to_le_bytes;
+		/// Appends `i16` in big-endian byte order.
+		push_i16_be, try_push_i16_be: i16 => to_be_bytes;
+		/// Appends `i32` in little-endian byte order.
+		push_i32_le, try_push_i32_le: i32 => to_le_bytes;
+		/// Appends `i32` in big-endian byte order.
+		push_i32_be, try_push_i32_be: i32 => to_be_bytes;
+		/// Appends `i64` in little-endian byte order.
+		push_i64_le, try_push_i64_le: i64 => to_le_bytes;
+		/// Appends `i64` in big-endian byte order.
+		push_i64_be, try_push_i64_be: i64 => to_be_bytes;
+		/// Appends `i128` in little-endian byte order.
+		push_i128_le, try_push_i128_le: i128 => to_le_bytes;
+		/// Appends `i128` in big-endian byte order.
+		push_i128_be, try_push_i128_be: i128 => to_be_bytes;
+
+		/// Appends `f32` (IEEE 754) in little-endian byte order.
+		push_f32_le, try_push_f32_le: f32 => to_le_bytes;
+		/// Appends `f32` (IEEE 754) in big-endian byte order.
+		push_f32_be, try_push_f32_be: f32 => to_be_bytes;
+		/// Appends `f64` (IEEE 754) in little-endian byte order.
+		push_f64_le, try_push_f64_le: f64 => to_le_bytes;
+		/// Appends `f64` (IEEE 754) in big-endian byte order.
+		push_f64_be, try_push_f64_be: f64 => to_be_bytes;
+	}
+
+	/// Appends `u64` as unsigned LEB128.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_uleb128(&mut self, value: u64) -> usize {
+		match self.try_push_uleb128(value) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `u64` as unsigned LEB128.
+	pub const fn try_push_uleb128(&mut self, mut value: u64) -> Result<usize, StackOverflow> {
+		let mut arr = [0u8; u64::MAX_LEB128_LEN];
+		let mut len = 0;
+		loop {
+			let byte = (value & 0x7F) as u8;
+			value >>= 7;
+			if value == 0 {
+				arr[len] = byte;
+				len += 1;
+				break;
+			}
+			arr[len] = byte | 0x80;
+			len += 1;
+		}
+
+		let (data, _) = arr.split_at(len);
+		self.try_write_bytes(data)
+	}
+
+	/// Appends `i64` as signed LEB128.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_sleb128(&mut self, value: i64) -> usize {
+		match self.try_push_sleb128(value) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `i64` as signed LEB128.
+	pub const fn try_push_sleb128(&mut self, mut value: i64) -> Result<usize, StackOverflow> {
+		let mut arr = [0u8; i64::MAX_LEB128_LEN];
+		let mut len = 0;
+		loop {
+			let byte = (value & 0x7F) as u8;
+			value >>= 7; // arithmetic shift keeps the sign
+			let done = (value == 0 && (byte & 0x40) == 0) || (value == -1 && (byte & 0x40) != 0);
+			if done {
+				arr[len] = byte;
+				len += 1;
+				break;
+			}
+			arr[len] = byte | 0x80;
+			len += 1;
+		}
+
+		let (data, _) = arr.split_at(len);
+		self.try_write_bytes(data)
+	}
+
+	/// Appends `i64` as zigzag-encoded unsigned LEB128 (protobuf `sint64`).
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	#[inline]
+	pub const fn push_zigzag(&mut self, value: i64) -> usize {
+		self.push_uleb128(zigzag_encode(value))
+	}
+
+	/// Appends `i64` as zigzag-encoded unsigned LEB128 (protobuf `sint64`).
+	#[inline]
+	pub const fn try_push_zigzag(&mut self, value: i64) -> Result<usize, StackOverflow> {
+		self.try_push_uleb128(zigzag_encode(value))
+	}
+}
+
+/// Maps signed integers to unsigned so that small magnitudes stay small:
+/// `0 => 0, -1 => 1, 1 => 2, -2 => 3, ...`
+#[inline]
+pub(crate) const fn zigzag_encode(value: i64) -> u64 {
+	((value << 1) ^ (value >> 63)) as u64
+}
@@ -0,0 +1,80 @@
+//! Safe UTF-8-aware character access for `ConstStrBuf`.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::Utf8SafeBuf;
+use crate::buf::utf8::decode_char_at;
+use crate::buf::utf8::is_continuation_byte;
+use crate::buf::utf8::last_char_start;
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Removes and returns the last character.
+	pub const fn pop_char(&mut self) -> Option<char> {
+		match last_char_start(self.as_bytes()) {
+			Some(start) => {
+				let (result, _) = decode_char_at(self.as_bytes(), start);
+				self.wpos = start;
+
+				Some(result)
+			}
+			None => None,
+		}
+	}
+
+	/// Returns the last character without removing it.
+	pub const fn last_char(&self) -> Option<char> {
+		match last_char_start(self.as_bytes()) {
+			Some(start) => Some(decode_char_at(self.as_bytes(), start).0),
+			None => None,
+		}
+	}
+
+	/// Number of characters (not bytes) written.
+	pub const fn char_count(&self) -> usize {
+		let bytes = self.as_bytes();
+		let mut count = 0;
+		let mut i = 0;
+		while i < bytes.len() {
+			if !is_continuation_byte(bytes[i]) {
+				count += 1;
+			}
+			i += 1;
+		}
+
+		count
+	}
+
+	/// Shortens the buffer to its first `n` characters; does nothing if `n >= char_count()`.
+	pub const fn truncate_chars(&mut self, n: usize) {
+		let bytes = self.as_bytes();
+		let mut count = 0;
+		let mut i = 0;
+		while i < bytes.len() {
+			if !is_continuation_byte(bytes[i]) {
+				if count == n {
+					self.wpos = i;
+					return;
+				}
+				count += 1;
+			}
+			i += 1;
+		}
+	}
+
+	/// Replaces the ASCII byte at `pos` with another ASCII byte, returning the old one.
+	///
+	/// Returns `None` and leaves the buffer unchanged if `pos` is out of bounds
+	/// or either byte is not ASCII, so UTF-8 validity is always preserved.
+	pub const fn set_ascii(&mut self, pos: usize, value: u8) -> Option<u8> {
+		if pos >= self.wpos || !value.is_ascii() {
+			return None;
+		}
+
+		let old = self.as_bytes()[pos];
+		if !old.is_ascii() {
+			return None;
+		}
+		self.buf[pos].write(value);
+
+		Some(old)
+	}
+}
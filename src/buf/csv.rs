@@ -0,0 +1,343 @@
+//! Const CSV writer with RFC 4180 quoting.
+
+use crate::buf::ConstStrBuf;
+use crate::buf::StackOverflow;
+use crate::buf::scratch::Scratch;
+
+/// Line terminator written by [`ConstCsvWriter::end_row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvLineEnding {
+	/// `\r\n`, as required by RFC 4180.
+	Crlf,
+	/// `\n`
+	Lf,
+}
+
+impl CsvLineEnding {
+	/// Returns the terminator.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Crlf => "\r\n",
+			Self::Lf => "\n",
+		}
+	}
+}
+
+/// Const CSV writer on top of a [`ConstStrBuf`].
+///
+/// Fields are separated automatically and quoted only if they contain the separator,
+/// `"`, `\r` or `\n`; quotes inside a quoted field are doubled. Every `try_` method either
+/// writes the whole field (with its separator) or nothing.
+///
+/// # Example
+/// ```rust
+/// use cluConstData::buf::ConstCsvWriter;
+///
+/// let mut csv = ConstCsvWriter::<64>::new();
+/// csv.row(&["sensor", "value"]);
+/// csv.field("temp, °C").field_i64(-12).end_row();
+/// csv.field("note \"a\"").field_u64(7).end_row();
+///
+/// assert_eq!(
+///	csv.as_str(),
+///	"sensor,value\r\n\"temp, °C\",-12\r\n\"note \"\"a\"\"\",7\r\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstCsvWriter<const CAP: usize> {
+	buf: ConstStrBuf<CAP>,
+	separator: u8,
+	line_ending: CsvLineEnding,
+	/// At least one field was written in the current row.
+	in_row: bool,
+}
+
+impl<const CAP: usize> Default for ConstCsvWriter<CAP> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Scratch space for the longest `i64`.
+type NumScratch = Scratch<{ "-9223372036854775808".len() }>;
+
+/// `true` if `field` must be enclosed in quotes.
+const fn needs_quotes(field: &[u8], separator: u8) -> bool {
+	let mut i = 0;
+	while i < field.len() {
+		if matches!(field[i], b'"' | b'\r' | b'\n') || field[i] == separator {
+			return true;
+		}
+		i += 1;
+	}
+
+	false
+}
+
+/// Number of bytes of `field` after quoting, if needed.
+const fn quoted_field_len(field: &str, separator: u8) -> usize {
+	let field = field.as_bytes();
+	if !needs_quotes(field, separator) {
+		return field.len();
+	}
+
+	let mut len = field.len() + 2;
+	let mut i = 0;
+	while i < field.len() {
+		if field[i] == b'"' {
+			len += 1;
+		}
+		i += 1;
+	}
+
+	len
+}
+
+impl<const CAP: usize> ConstCsvWriter<CAP> {
+	/// Creates a writer using `,` and `\r\n`.
+	#[inline]
+	pub const fn new() -> Self {
+		Self::with_format(b',', CsvLineEnding::Crlf)
+	}
+
+	/// Creates a writer using a custom separator and line terminator.
+	///
+	/// Panics if `separator` is not ASCII, or is `"`, `\r` or `\n`.
+	#[track_caller]
+	pub const fn with_format(separator: u8, line_ending: CsvLineEnding) -> Self {
+		if !separator.is_ascii() || matches!(separator, b'"' | b'\r' | b'\n') {
+			panic!("ConstCsvWriter: invalid separator");
+		}
+
+		Self {
+			buf: ConstStrBuf::new(),
+			separator,
+			line_ending,
+			in_row: false,
+		}
+	}
+
+	/// Returns the CSV written so far.
+	#[inline]
+	pub const fn as_str(&self) -> &str {
+		self.buf.as_str()
+	}
+
+	/// Returns the underlying buffer.
+	#[inline]
+	pub const fn into_inner(self) -> ConstStrBuf<CAP> {
+		self.buf
+	}
+
+	/// Appends a text field, quoting it if needed.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn field(&mut self, field: &str) -> &mut Self {
+		match self.try_field(field) {
+			Ok(()) => self,
+			Err(_) => ConstStrBuf::<CAP>::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a text field, quoting it if needed.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_field(&mut self, field: &str) -> Result<(), StackOverflow> {
+		let len = self.in_row as usize + quoted_field_len(field, self.separator);
+		if len > self.buf.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		if self.in_row {
+			let _ = self.buf.__try_write_byte(self.separator);
+		}
+		self.in_row = true;
+		let bytes = field.as_bytes();
+		if !needs_quotes(bytes, self.separator) {
+			let _ = self.buf.try_push_str(field);
+			return Ok(());
+		}
+
+		let _ = self.buf.__try_write_byte(b'"');
+		let mut start = 0;
+		let mut i = 0;
+		while i < bytes.len() {
+			if bytes[i] == b'"' {
+				// the chunk ends after the quote, which is then written once more
+				let (_, rest) = bytes.split_at(start);
+				let (chunk, _) = rest.split_at(i + 1 - start);
+				let _ = self.buf.__try_write_bytes_unchecked(chunk); // split at ASCII `"`, still UTF-8
+				let _ = self.buf.__try_write_byte(b'"');
+				start = i + 1;
+			}
+			i += 1;
+		}
+		let (_, rest) = bytes.split_at(start);
+		let _ = self.buf.__try_write_bytes_unchecked(rest);
+		let _ = self.buf.__try_write_byte(b'"');
+
+		Ok(())
+	}
+
+	/// Appends an unsigned integer field.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn field_u64(&mut self, value: u64) -> &mut Self {
+		match self.try_field_u64(value) {
+			Ok(()) => self,
+			Err(_) => ConstStrBuf::<CAP>::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends an unsigned integer field.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_field_u64(&mut self, value: u64) -> Result<(), StackOverflow> {
+		let mut out = NumScratch::new();
+		out.push_decimal(value, 1);
+
+		self.try_number(&out)
+	}
+
+	/// Appends a signed integer field.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn field_i64(&mut self, value: i64) -> &mut Self {
+		match self.try_field_i64(value) {
+			Ok(()) => self,
+			Err(_) => ConstStrBuf::<CAP>::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a signed integer field.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_field_i64(&mut self, value: i64) -> Result<(), StackOverflow> {
+		let mut out = NumScratch::new();
+		if value < 0 {
+			out.push_byte(b'-');
+		}
+		out.push_decimal(value.unsigned_abs(), 1);
+
+		self.try_number(&out)
+	}
+
+	const fn try_number(&mut self, out: &NumScratch) -> Result<(), StackOverflow> {
+		let digits = out.as_bytes();
+		if self.in_row as usize + digits.len() > self.buf.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		if self.in_row {
+			let _ = self.buf.__try_write_byte(self.separator);
+		}
+		self.in_row = true;
+		let _ = self.buf.__try_write_bytes_unchecked(digits); // ASCII only
+
+		Ok(())
+	}
+
+	/// Terminates the current row.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn end_row(&mut self) -> &mut Self {
+		match self.try_end_row() {
+			Ok(()) => self,
+			Err(_) => ConstStrBuf::<CAP>::cold_overflow_panic(),
+		}
+	}
+
+	/// Terminates the current row.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_end_row(&mut self) -> Result<(), StackOverflow> {
+		match self.buf.try_push_str(self.line_ending.as_str()) {
+			Ok(_) => {
+				self.in_row = false;
+				Ok(())
+			}
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Appends a complete row of text fields, e.g. a header.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn row(&mut self, fields: &[&str]) -> &mut Self {
+		match self.try_row(fields) {
+			Ok(()) => self,
+			Err(_) => ConstStrBuf::<CAP>::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a complete row of text fields, e.g. a header.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_row(&mut self, fields: &[&str]) -> Result<(), StackOverflow> {
+		let mut len = self.line_ending.as_str().len();
+		let mut i = 0;
+		while i < fields.len() {
+			len += quoted_field_len(fields[i], self.separator);
+			if i > 0 || self.in_row {
+				len += 1;
+			}
+			i += 1;
+		}
+		if len > self.buf.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let mut i = 0;
+		while i < fields.len() {
+			let _ = self.try_field(fields[i]);
+			i += 1;
+		}
+		let _ = self.try_end_row();
+
+		Ok(())
+	}
+}
+
+/// Compile-time CSV row (e.g. a header) of a `&[&str]` constant as `&'static str`.
+///
+/// Uses `,` and `\r\n` unless a separator byte and a
+/// [`CsvLineEnding`](crate::buf::CsvLineEnding) variant are given.
+/// The result can be used directly in [`const_data!`](crate::const_data).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::{const_data, csv_row};
+///
+/// const COLUMNS: &[&str] = &["time", "temp, °C", "humidity"];
+/// const_data! {
+///	const LOG_HEADER: &str = "# sensor log\n", csv_row!(COLUMNS, b';', Lf);
+/// }
+///
+/// assert_eq!(csv_row!(COLUMNS), "time,\"temp, °C\",humidity\r\n");
+/// assert_eq!(LOG_HEADER, "# sensor log\ntime;temp, °C;humidity\n");
+/// ```
+#[macro_export]
+macro_rules! csv_row {
+	[$fields: expr $(,)?] => {
+		$crate::csv_row!($fields, b',', Crlf)
+	};
+	[$fields: expr, $separator: expr, $line_ending: ident $(,)?] => {
+		$crate::__buf_str!($crate::buf::size::csv_row_max_len($fields), {
+			let mut csv = $crate::buf::ConstCsvWriter::with_format(
+				$separator,
+				$crate::buf::CsvLineEnding::$line_ending,
+			);
+			csv.row($fields);
+			csv.into_inner()
+		})
+	};
+}
@@ -0,0 +1,411 @@
+//! Mid-buffer editing: insertion, removal, truncation and range replacement.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use crate::buf::DefBuf;
+use crate::buf::NulTermBuf;
+use crate::buf::StackOverflow;
+use crate::buf::Utf8SafeBuf;
+use crate::buf::size::ConstByteBufSize;
+use crate::buf::utf8::decode_char_at;
+use crate::buf::utf8::is_continuation_byte;
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Replaces bytes in `start..end` with `data`, shifting the tail as needed.
+	///
+	/// Panics if the range is out of bounds.
+	#[track_caller]
+	const fn _try_replace_range(
+		&mut self,
+		start: usize,
+		end: usize,
+		data: &[u8],
+	) -> Result<usize, StackOverflow> {
+		if start > end || end > self.wpos {
+			Self::cold_out_of_bounds_panic();
+		}
+
+		let removed = end - start;
+		let datalen = data.len();
+		if self.wpos - removed + datalen > Self::DATA_CAP {
+			return Err(StackOverflow);
+		}
+
+		if datalen > removed {
+			// shift tail right, from the back
+			let shift = datalen - removed;
+			let mut i = self.wpos;
+			while i > end {
+				i -= 1;
+				self.buf[i + shift] = self.buf[i];
+			}
+		} else if datalen < removed {
+			// shift tail left, from the front
+			let shift = removed - datalen;
+			let mut i = end;
+			while i < self.wpos {
+				self.buf[i - shift] = self.buf[i];
+				i += 1;
+			}
+		}
+
+		let mut i = 0;
+		while i < datalen {
+			self.buf[start + i].write(data[i]);
+			i += 1;
+		}
+		self.wpos = self.wpos - removed + datalen;
+		self._sync_nul();
+
+		Ok(datalen)
+	}
+
+	/// Shortens the buffer to `new_len` bytes; does nothing if `new_len >= len()`.
+	#[inline]
+	const fn _truncate(&mut self, new_len: usize) {
+		if new_len < self.wpos {
+			self.wpos = new_len;
+			self._sync_nul();
+		}
+	}
+
+	/// Checks that `pos` lies on a UTF-8 char boundary of the written data.
+	///
+	/// Panics otherwise.
+	#[track_caller]
+	const fn _assert_char_boundary(&self, pos: usize) {
+		if pos > self.wpos {
+			Self::cold_out_of_bounds_panic();
+		}
+		let (_, tail) = self.as_bytes().split_at(pos);
+		if let [b, ..] = tail
+			&& is_continuation_byte(*b)
+		{
+			Self::cold_char_boundary_panic();
+		}
+	}
+
+	/// Panics when a position does not lie on a UTF-8 char boundary.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_char_boundary_panic() -> ! {
+		panic!("ConstStrBuf: position is not a char boundary");
+	}
+}
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Inserts a string at byte position `pos`.
+	///
+	/// Panics on overflow, or if `pos` is out of bounds or not a char boundary.
+	#[track_caller]
+	pub const fn insert_str(&mut self, pos: usize, s: &str) -> usize {
+		match self.try_insert_str(pos, s) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Inserts a string at byte position `pos`.
+	///
+	/// Panics if `pos` is out of bounds or not a char boundary.
+	#[track_caller]
+	pub const fn try_insert_str(&mut self, pos: usize, s: &str) -> Result<usize, StackOverflow> {
+		self._assert_char_boundary(pos);
+		self._try_replace_range(pos, pos, s.as_bytes())
+	}
+
+	/// Inserts a character at byte position `pos`.
+	///
+	/// Panics on overflow, or if `pos` is out of bounds or not a char boundary.
+	#[track_caller]
+	pub const fn insert_char(&mut self, pos: usize, value: char) -> usize {
+		match self.try_insert_char(pos, value) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Inserts a character at byte position `pos`.
+	///
+	/// Panics if `pos` is out of bounds or not a char boundary.
+	#[track_caller]
+	pub const fn try_insert_char(
+		&mut self,
+		pos: usize,
+		value: char,
+	) -> Result<usize, StackOverflow> {
+		let mut buf = [0u8; <char as ConstByteBufSize>::MAX_DECIMAL_LEN];
+		let str = value.encode_utf8(&mut buf);
+
+		self.try_insert_str(pos, str)
+	}
+
+	/// Inserts a string at the beginning of the buffer.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	#[inline]
+	pub const fn push_front_str(&mut self, s: &str) -> usize {
+		self.insert_str(0, s)
+	}
+
+	/// Inserts a string at the beginning of the buffer.
+	#[inline]
+	pub const fn try_push_front_str(&mut self, s: &str) -> Result<usize, StackOverflow> {
+		self.try_insert_str(0, s)
+	}
+
+	/// Removes and returns the character starting at byte position `pos`.
+	///
+	/// Panics if `pos` is out of bounds or not a char boundary.
+	#[track_caller]
+	pub const fn remove(&mut self, pos: usize) -> char {
+		if pos >= self.wpos {
+			Self::cold_out_of_bounds_panic();
+		}
+		self._assert_char_boundary(pos);
+
+		let (result, len) = decode_char_at(self.as_bytes(), pos);
+		self.drain_range(pos, pos + len);
+
+		result
+	}
+
+	/// Shortens the buffer to `new_len` bytes; does nothing if `new_len >= len()`.
+	///
+	/// Panics if `new_len` is not a char boundary.
+	#[track_caller]
+	pub const fn truncate(&mut self, new_len: usize) {
+		if new_len < self.wpos {
+			self._assert_char_boundary(new_len);
+			self._truncate(new_len);
+		}
+	}
+
+	/// Removes bytes in `start..end`, returning the number of removed bytes.
+	///
+	/// Panics if the range is out of bounds or its ends are not char boundaries.
+	#[track_caller]
+	pub const fn drain_range(&mut self, start: usize, end: usize) -> usize {
+		self.replace_range(start, end, "");
+
+		end - start
+	}
+
+	/// Replaces bytes in `start..end` with `s`.
+	///
+	/// Panics on overflow, or if the range is out of bounds or its ends are not char boundaries.
+	#[track_caller]
+	pub const fn replace_range(&mut self, start: usize, end: usize, s: &str) -> usize {
+		match self.try_replace_range(start, end, s) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Replaces bytes in `start..end` with `s`.
+	///
+	/// Panics if the range is out of bounds or its ends are not char boundaries.
+	#[track_caller]
+	pub const fn try_replace_range(
+		&mut self,
+		start: usize,
+		end: usize,
+		s: &str,
+	) -> Result<usize, StackOverflow> {
+		if start > end {
+			Self::cold_out_of_bounds_panic();
+		}
+		self._assert_char_boundary(start);
+		self._assert_char_boundary(end);
+
+		self._try_replace_range(start, end, s.as_bytes())
+	}
+}
+
+/// Generates the raw byte editing methods of `DefBuf` and `NulTermBuf`.
+macro_rules! impl_byte_edit {
+	[ $($tdata:ident),* $(,)? ] => {
+		$(
+			impl<const CAP: usize> ConstByteBuf<CAP, $tdata> {
+				/// Inserts raw bytes at position `pos`.
+				///
+				/// Panics on overflow or if `pos` is out of bounds.
+				#[track_caller]
+				pub const fn insert_bytes(&mut self, pos: usize, data: &[u8]) -> usize {
+					match self.try_insert_bytes(pos, data) {
+						Ok(a) => a,
+						Err(_) => Self::cold_overflow_panic(),
+					}
+				}
+
+				/// Inserts raw bytes at position `pos`.
+				///
+				/// Panics if `pos` is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn try_insert_bytes(
+					&mut self,
+					pos: usize,
+					data: &[u8],
+				) -> Result<usize, StackOverflow> {
+					self._try_replace_range(pos, pos, data)
+				}
+
+				/// Inserts a byte at position `pos`.
+				///
+				/// Panics on overflow or if `pos` is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn insert_byte(&mut self, pos: usize, data: u8) -> usize {
+					self.insert_bytes(pos, &[data])
+				}
+
+				/// Inserts a byte at position `pos`.
+				///
+				/// Panics if `pos` is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn try_insert_byte(&mut self, pos: usize, data: u8) -> Result<usize, StackOverflow> {
+					self.try_insert_bytes(pos, &[data])
+				}
+
+				/// Inserts a string at position `pos`.
+				///
+				/// Panics on overflow or if `pos` is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn insert_str(&mut self, pos: usize, s: &str) -> usize {
+					self.insert_bytes(pos, s.as_bytes())
+				}
+
+				/// Inserts a string at position `pos`.
+				///
+				/// Panics if `pos` is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn try_insert_str(&mut self, pos: usize, s: &str) -> Result<usize, StackOverflow> {
+					self.try_insert_bytes(pos, s.as_bytes())
+				}
+
+				/// Inserts a UTF-8 encoded character at position `pos`.
+				///
+				/// Panics on overflow or if `pos` is out of bounds.
+				#[track_caller]
+				pub const fn insert_char(&mut self, pos: usize, value: char) -> usize {
+					match self.try_insert_char(pos, value) {
+						Ok(a) => a,
+						Err(_) => Self::cold_overflow_panic(),
+					}
+				}
+
+				/// Inserts a UTF-8 encoded character at position `pos`.
+				///
+				/// Panics if `pos` is out of bounds.
+				#[track_caller]
+				pub const fn try_insert_char(
+					&mut self,
+					pos: usize,
+					value: char,
+				) -> Result<usize, StackOverflow> {
+					let mut buf = [0u8; <char as ConstByteBufSize>::MAX_DECIMAL_LEN];
+					let str = value.encode_utf8(&mut buf);
+
+					self.try_insert_bytes(pos, str.as_bytes())
+				}
+
+				/// Inserts raw bytes at the beginning of the buffer.
+				///
+				/// Panics on overflow.
+				#[track_caller]
+				#[inline]
+				pub const fn push_front_bytes(&mut self, data: &[u8]) -> usize {
+					self.insert_bytes(0, data)
+				}
+
+				/// Inserts raw bytes at the beginning of the buffer.
+				#[inline]
+				pub const fn try_push_front_bytes(&mut self, data: &[u8]) -> Result<usize, StackOverflow> {
+					self.try_insert_bytes(0, data)
+				}
+
+				/// Inserts a string at the beginning of the buffer.
+				///
+				/// Panics on overflow.
+				#[track_caller]
+				#[inline]
+				pub const fn push_front_str(&mut self, s: &str) -> usize {
+					self.insert_bytes(0, s.as_bytes())
+				}
+
+				/// Inserts a string at the beginning of the buffer.
+				#[inline]
+				pub const fn try_push_front_str(&mut self, s: &str) -> Result<usize, StackOverflow> {
+					self.try_insert_bytes(0, s.as_bytes())
+				}
+
+				/// Removes and returns the byte at position `pos`.
+				///
+				/// Panics if `pos` is out of bounds.
+				#[track_caller]
+				pub const fn remove(&mut self, pos: usize) -> u8 {
+					if pos >= self.wpos {
+						Self::cold_out_of_bounds_panic();
+					}
+
+					let result = self.as_bytes()[pos];
+					self.drain_range(pos, pos + 1);
+
+					result
+				}
+
+				/// Shortens the buffer to `new_len` bytes; does nothing if `new_len >= len()`.
+				#[inline]
+				pub const fn truncate(&mut self, new_len: usize) {
+					self._truncate(new_len)
+				}
+
+				/// Removes bytes in `start..end`, returning the number of removed bytes.
+				///
+				/// Panics if the range is out of bounds.
+				#[track_caller]
+				pub const fn drain_range(&mut self, start: usize, end: usize) -> usize {
+					self.replace_range(start, end, &[]);
+
+					end - start
+				}
+
+				/// Replaces bytes in `start..end` with `data`.
+				///
+				/// Panics on overflow or if the range is out of bounds.
+				#[track_caller]
+				pub const fn replace_range(&mut self, start: usize, end: usize, data: &[u8]) -> usize {
+					match self._try_replace_range(start, end, data) {
+						Ok(a) => a,
+						Err(_) => Self::cold_overflow_panic(),
+					}
+				}
+
+				/// Replaces bytes in `start..end` with `data`.
+				///
+				/// Panics if the range is out of bounds.
+				#[track_caller]
+				#[inline]
+				pub const fn try_replace_range(
+					&mut self,
+					start: usize,
+					end: usize,
+					data: &[u8],
+				) -> Result<usize, StackOverflow> {
+					self._try_replace_range(start, end, data)
+				}
+			}
+		)*
+	};
+}
+
+impl_byte_edit! {
+	DefBuf,
+	NulTermBuf,
+}
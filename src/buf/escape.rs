@@ -0,0 +1,346 @@
+//! Const string escaping: Rust debug, JSON, C and HTML.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::HexCase;
+use crate::buf::StackOverflow;
+use crate::buf::Utf8SafeBuf;
+use crate::buf::scratch::Scratch;
+
+/// Escaping mode of [`ConstStrBuf::push_escaped`](crate::buf::ConstStrBuf::push_escaped).
+///
+/// Characters that need no escaping, including all non-ASCII text except C1
+/// controls in `Debug` mode, are written unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Escape {
+	/// Rust string literal: `\"`, `\\`, `\n`, `\r`, `\t`, `\0` and `\u{7f}` for other
+	/// control characters. Unlike `core`, other non-printable characters are kept.
+	Debug,
+	/// JSON string: `\"`, `\\`, `\b`, `\f`, `\n`, `\r`, `\t` and `\u001f` for other
+	/// control characters.
+	Json,
+	/// C string literal: `\"`, `\\`, `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v` and
+	/// three-digit octal (`\177`) for other control characters.
+	C,
+	/// HTML text or attribute value: `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&#39;`.
+	Html,
+}
+
+impl Escape {
+	/// Maximum number of output bytes per input byte.
+	pub const fn max_expansion(self) -> usize {
+		match self {
+			Self::Debug => r"\u{1f}".len(),
+			Self::Json => r"\u001f".len(),
+			Self::C => r"\177".len(),
+			Self::Html => "&quot;".len(),
+		}
+	}
+
+	/// Escapes the character starting at `s[i]`.
+	///
+	/// Returns the output and the number of consumed input bytes.
+	const fn escape_at(self, s: &[u8], i: usize) -> (EscapeScratch, usize) {
+		let mut out = EscapeScratch::new();
+		let c = s[i];
+		let simple: &[u8] = match (self, c) {
+			(Self::Debug | Self::Json | Self::C, b'"') => br#"\""#,
+			(Self::Debug | Self::Json | Self::C, b'\\') => br"\\",
+			(Self::Debug | Self::Json | Self::C, b'\n') => br"\n",
+			(Self::Debug | Self::Json | Self::C, b'\r') => br"\r",
+			(Self::Debug | Self::Json | Self::C, b'\t') => br"\t",
+			(Self::Debug, b'\0') => br"\0",
+			(Self::Json | Self::C, 0x08) => br"\b",
+			(Self::Json | Self::C, 0x0C) => br"\f",
+			(Self::C, 0x07) => br"\a",
+			(Self::C, 0x0B) => br"\v",
+			(Self::Html, b'&') => b"&amp;",
+			(Self::Html, b'<') => b"&lt;",
+			(Self::Html, b'>') => b"&gt;",
+			(Self::Html, b'"') => b"&quot;",
+			(Self::Html, b'\'') => b"&#39;",
+			_ => b"",
+		};
+		if !simple.is_empty() {
+			out.push(simple);
+			return (out, 1);
+		}
+
+		match self {
+			Self::Debug if c < 0x20 || c == 0x7F => {
+				out.push(br"\u{");
+				out.push_hex(c as u64);
+				out.push_byte(b'}');
+			}
+			// C1 controls U+0080..=U+009F are encoded as `C2 80..=9F`
+			Self::Debug if c == 0xC2 && i + 1 < s.len() && s[i + 1] <= 0x9F => {
+				out.push(br"\u{");
+				out.push_hex(s[i + 1] as u64);
+				out.push_byte(b'}');
+				return (out, 2);
+			}
+			Self::Json if c < 0x20 => {
+				out.push(br"\u00");
+				out.push(&HexCase::Lower.encode(c));
+			}
+			Self::C if c < 0x20 || c == 0x7F => {
+				out.push(&[
+					b'\\',
+					b'0' + (c >> 6),
+					b'0' + ((c >> 3) & 7),
+					b'0' + (c & 7),
+				]);
+			}
+			_ => out.push_byte(c),
+		}
+
+		(out, 1)
+	}
+
+	/// Number of bytes of `s` after escaping.
+	const fn escaped_len(self, s: &str) -> usize {
+		let s = s.as_bytes();
+		let mut len = 0;
+		let mut i = 0;
+		while i < s.len() {
+			let (out, consumed) = self.escape_at(s, i);
+			len += out.as_bytes().len();
+			i += consumed;
+		}
+
+		len
+	}
+}
+
+/// Scratch space for a single escaped character.
+type EscapeScratch = Scratch<6>;
+
+/// Escaped form of `byte` inside a `b"..."` literal, as `<[u8]>::escape_ascii`.
+const fn escape_ascii(byte: u8) -> ([u8; 4], usize) {
+	match byte {
+		b'\t' => (*br"\t  ", 2),
+		b'\r' => (*br"\r  ", 2),
+		b'\n' => (*br"\n  ", 2),
+		b'\\' => (*br"\\  ", 2),
+		b'\'' => (*br"\'  ", 2),
+		b'"' => (*br#"\"  "#, 2),
+		0x20..=0x7E => ([byte, 0, 0, 0], 1),
+		_ => {
+			let [hi, lo] = HexCase::Lower.encode(byte);
+			([b'\\', b'x', hi, lo], 4)
+		}
+	}
+}
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Appends `s` escaped with `escape`, without surrounding quotes.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::{ConstStrBuf, Escape};
+	///
+	/// let mut buf = ConstStrBuf::<32>::new();
+	/// buf.push_escaped("say \"hi\"\n", Escape::Json);
+	/// assert_eq!(buf, r#"say \"hi\"\n"#);
+	///
+	/// buf.clear();
+	/// buf.push_escaped("a < b & c", Escape::Html);
+	/// assert_eq!(buf, "a &lt; b &amp; c");
+	/// ```
+	#[track_caller]
+	pub const fn push_escaped(&mut self, s: &str, escape: Escape) -> usize {
+		match self.try_push_escaped(s, escape) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `s` escaped with `escape`, without surrounding quotes.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_escaped(
+		&mut self,
+		s: &str,
+		escape: Escape,
+	) -> Result<usize, StackOverflow> {
+		let len = escape.escaped_len(s);
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let s = s.as_bytes();
+		let mut i = 0;
+		while i < s.len() {
+			let (out, consumed) = escape.escape_at(s, i);
+			let _ = self.__try_write_bytes_unchecked(out.as_bytes()); // whole chars are written in order
+			i += consumed;
+		}
+
+		Ok(len)
+	}
+
+	/// Appends `s` escaped with `escape` and enclosed in `"`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_quoted(&mut self, s: &str, escape: Escape) -> usize {
+		match self.try_push_quoted(s, escape) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `s` escaped with `escape` and enclosed in `"`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_quoted(
+		&mut self,
+		s: &str,
+		escape: Escape,
+	) -> Result<usize, StackOverflow> {
+		let len = escape.escaped_len(s) + 2;
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let _ = self.__try_write_byte(b'"');
+		let _ = self.try_push_escaped(s, escape);
+		let _ = self.__try_write_byte(b'"');
+
+		Ok(len)
+	}
+
+	/// Appends `data` as a Rust byte string literal: `b"ab\x00\n"`.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::ConstStrBuf;
+	///
+	/// let mut buf = ConstStrBuf::<32>::new();
+	/// buf.push_debug_bytes(b"ok\x00\xff\"");
+	/// assert_eq!(buf, r#"b"ok\x00\xff\"""#);
+	/// ```
+	#[track_caller]
+	pub const fn push_debug_bytes(&mut self, data: &[u8]) -> usize {
+		match self.try_push_debug_bytes(data) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `data` as a Rust byte string literal: `b"ab\x00\n"`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_debug_bytes(&mut self, data: &[u8]) -> Result<usize, StackOverflow> {
+		let mut len = br#"b"""#.len();
+		let mut i = 0;
+		while i < data.len() {
+			len += escape_ascii(data[i]).1;
+			i += 1;
+		}
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let _ = self.__try_write_bytes_unchecked(br#"b""#);
+		let mut i = 0;
+		while i < data.len() {
+			let (escaped, escaped_len) = escape_ascii(data[i]);
+			let (escaped, _) = escaped.split_at(escaped_len);
+			let _ = self.__try_write_bytes_unchecked(escaped); // ASCII only
+			i += 1;
+		}
+		let _ = self.__try_write_byte(b'"');
+
+		Ok(len)
+	}
+}
+
+/// Compile-time escaped string as `&'static str`, without surrounding quotes.
+///
+/// The mode defaults to [`Debug`](crate::buf::Escape::Debug); pass `Debug`, `Json`,
+/// `C` or `Html` as the second argument to select another one.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::escape_str;
+///
+/// const NAME: &str = "tab\there";
+/// const RUST: &str = escape_str!(NAME);
+/// const C: &str = escape_str!("bell\x07", C);
+/// const HTML: &str = escape_str!("<b>", Html);
+///
+/// assert_eq!(RUST, r"tab\there");
+/// assert_eq!(C, r"bell\a");
+/// assert_eq!(HTML, "&lt;b&gt;");
+/// ```
+#[macro_export]
+macro_rules! escape_str {
+	[$s: expr $(,)?] => {
+		$crate::escape_str!($s, Debug)
+	};
+	[$s: expr, $escape: ident $(,)?] => {
+		$crate::__buf_str!(
+			$crate::buf::size::escaped_max_len($s.len(), $crate::buf::Escape::$escape),
+			{
+				let mut buf = $crate::buf::ConstStrBuf::new();
+				buf.push_escaped($s, $crate::buf::Escape::$escape);
+				buf
+			}
+		)
+	};
+}
+
+/// Compile-time escaped string enclosed in `"` as `&'static str`.
+///
+/// Takes the same arguments as [`escape_str!`](crate::escape_str).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::quoted_str;
+///
+/// const KEY: &str = quoted_str!("line\nbreak", Json);
+/// assert_eq!(KEY, r#""line\nbreak""#);
+/// ```
+#[macro_export]
+macro_rules! quoted_str {
+	[$s: expr $(,)?] => {
+		$crate::quoted_str!($s, Debug)
+	};
+	[$s: expr, $escape: ident $(,)?] => {
+		$crate::__buf_str!(
+			$crate::buf::size::escaped_max_len($s.len(), $crate::buf::Escape::$escape) + 2,
+			{
+				let mut buf = $crate::buf::ConstStrBuf::new();
+				buf.push_quoted($s, $crate::buf::Escape::$escape);
+				buf
+			}
+		)
+	};
+}
+
+/// Compile-time Rust byte string literal (`b"..\x00"`) of a byte slice as `&'static str`.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::debug_bytes_str;
+///
+/// const MAGIC: &str = debug_bytes_str!(b"\x7fELF\x02");
+/// assert_eq!(MAGIC, r#"b"\x7fELF\x02""#);
+/// ```
+#[macro_export]
+macro_rules! debug_bytes_str {
+	[$data: expr $(,)?] => {
+		$crate::__buf_str!($crate::buf::size::debug_bytes_max_len($data.len()), {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_debug_bytes($data);
+			buf
+		})
+	};
+}
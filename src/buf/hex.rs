@@ -0,0 +1,121 @@
+//! Const hexadecimal encoding.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::StackOverflow;
+use crate::buf::Utf8SafeBuf;
+use crate::buf::size::hex_encoded_len;
+
+/// Letter case of hexadecimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexCase {
+	/// `0-9a-f`
+	Lower,
+	/// `0-9A-F`
+	Upper,
+}
+
+impl HexCase {
+	/// Digit table for this case.
+	pub(crate) const fn digits(self) -> &'static [u8; 16] {
+		match self {
+			Self::Lower => b"0123456789abcdef",
+			Self::Upper => b"0123456789ABCDEF",
+		}
+	}
+
+	/// Encodes one byte as two hexadecimal digits.
+	#[inline]
+	pub(crate) const fn encode(self, byte: u8) -> [u8; 2] {
+		let digits = self.digits();
+		[digits[(byte >> 4) as usize], digits[(byte & 0xF) as usize]]
+	}
+}
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Appends `data` as hexadecimal digit pairs, with `separator` between the pairs.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::{ConstStrBuf, HexCase};
+	/// use cluConstData::buf::size::hex_encoded_len;
+	///
+	/// const KEY: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+	/// const FINGERPRINT: ConstStrBuf<{ hex_encoded_len(KEY.len(), ":") }> = {
+	///	let mut buf = ConstStrBuf::new();
+	///	buf.push_hex_bytes(&KEY, HexCase::Lower, ":");
+	///	buf
+	/// };
+	/// assert_eq!(FINGERPRINT, "de:ad:be:ef");
+	/// ```
+	#[track_caller]
+	pub const fn push_hex_bytes(&mut self, data: &[u8], case: HexCase, separator: &str) -> usize {
+		match self.try_push_hex_bytes(data, case, separator) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `data` as hexadecimal digit pairs, with `separator` between the pairs.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_hex_bytes(
+		&mut self,
+		data: &[u8],
+		case: HexCase,
+		separator: &str,
+	) -> Result<usize, StackOverflow> {
+		let len = hex_encoded_len(data.len(), separator);
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let mut i = 0;
+		while i < data.len() {
+			if i > 0 {
+				let _ = self.__try_write_bytes_unchecked(separator.as_bytes());
+			}
+			let _ = self.__try_write_bytes_unchecked(&case.encode(data[i]));
+			i += 1;
+		}
+
+		Ok(len)
+	}
+}
+
+/// Compile-time hexadecimal representation of bytes as `&'static str`.
+///
+/// Takes the bytes (`&[u8]` or `[u8; N]`), then optionally the case (`Lower` by default)
+/// and a separator string (none by default).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::{concat_bytes, hex_str};
+///
+/// const BLOB: &[u8] = concat_bytes!(&[0xde, 0xad], &[0xbe, 0xef]);
+///
+/// assert_eq!(hex_str!(BLOB), "deadbeef");
+/// assert_eq!(hex_str!(BLOB, Upper), "DEADBEEF");
+/// assert_eq!(hex_str!(BLOB, Lower, ":"), "de:ad:be:ef");
+/// ```
+#[macro_export]
+macro_rules! hex_str {
+	[$bytes: expr $(,)?] => {
+		$crate::hex_str!($bytes, Lower, "")
+	};
+	[$bytes: expr, $case: ident $(,)?] => {
+		$crate::hex_str!($bytes, $case, "")
+	};
+	[$bytes: expr, $case: ident, $separator: expr $(,)?] => {{
+		const _BYTES: &[u8] = &$bytes;
+		const _SEPARATOR: &str = $separator;
+
+		$crate::__buf_str!($crate::buf::size::hex_encoded_len(_BYTES.len(), _SEPARATOR), {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_hex_bytes(_BYTES, $crate::buf::HexCase::$case, _SEPARATOR);
+			buf
+		})
+	}};
+}
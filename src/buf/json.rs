@@ -0,0 +1,300 @@
+//! Const JSON writer.
+
+use crate::buf::ConstStrBuf;
+use crate::buf::Escape;
+use core::fmt::Display;
+
+/// Const JSON writer on top of a [`ConstStrBuf`].
+///
+/// Commas, colons and string escaping are handled by the writer; it tracks up to 64
+/// levels of nested objects and arrays. The first misuse or overflow is remembered,
+/// all later calls are ignored and [`finish`](Self::finish) returns the error.
+///
+/// # Example
+/// ```rust
+/// use cluConstData::buf::{ConstJsonWriter, ConstStrBuf};
+///
+/// const STATUS: ConstStrBuf<64> = {
+///	let mut json = ConstJsonWriter::new();
+///	json.begin_object();
+///	json.key("ok").value_bool(true);
+///	json.key("uptime").value_u64(3600);
+///	json.key("tags").begin_array().value_str("a\"b").null().end();
+///	json.end();
+///
+///	match json.finish() {
+///		Ok(a) => a,
+///		Err(e) => panic!("{}", e.as_str()),
+///	}
+/// };
+/// assert_eq!(STATUS, r#"{"ok":true,"uptime":3600,"tags":["a\"b",null]}"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstJsonWriter<const CAP: usize> {
+	buf: ConstStrBuf<CAP>,
+	/// One bit per open container, `1` for an object; the innermost one is bit 0.
+	containers: u64,
+	depth: u32,
+	state: JsonState,
+	error: Option<JsonError>,
+}
+
+/// Position of the writer within the current container (or the top level).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum JsonState {
+	/// Nothing written yet.
+	Empty,
+	/// A value was written, the next one needs a comma.
+	AfterValue,
+	/// An object key was written, a value must follow.
+	AfterKey,
+}
+
+/// Maximum nesting of objects and arrays.
+const MAX_DEPTH: u32 = u64::BITS;
+
+/// Error type returned by [`ConstJsonWriter::finish`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonError {
+	/// The buffer has not enough space left.
+	Overflow,
+	/// A key was written outside an object or right after another key.
+	UnexpectedKey,
+	/// A value was written inside an object without a key.
+	ExpectedKey,
+	/// An object was closed right after a key.
+	MissingValue,
+	/// `end` was called with no open object or array.
+	UnbalancedEnd,
+	/// More than 64 nested objects and arrays.
+	TooDeep,
+	/// A second value was written at the top level.
+	MultipleRoots,
+	/// An object or array is still open, or nothing was written.
+	Incomplete,
+}
+
+impl JsonError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Overflow => "ConstByteBuf overflow: capacity exceeded",
+			Self::UnexpectedKey => "invalid JSON: unexpected key",
+			Self::ExpectedKey => "invalid JSON: expected a key",
+			Self::MissingValue => "invalid JSON: missing value after key",
+			Self::UnbalancedEnd => "invalid JSON: `end` without an open object or array",
+			Self::TooDeep => "invalid JSON: nesting too deep",
+			Self::MultipleRoots => "invalid JSON: more than one top-level value",
+			Self::Incomplete => "invalid JSON: incomplete document",
+		}
+	}
+}
+
+impl Display for JsonError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for JsonError {}
+
+impl<const CAP: usize> Default for ConstJsonWriter<CAP> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const CAP: usize> ConstJsonWriter<CAP> {
+	/// Creates an empty writer.
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			buf: ConstStrBuf::new(),
+			containers: 0,
+			depth: 0,
+			state: JsonState::Empty,
+			error: None,
+		}
+	}
+
+	/// Returns the first error, if any.
+	#[inline]
+	pub const fn error(&self) -> Option<JsonError> {
+		self.error
+	}
+
+	/// Returns the JSON written so far.
+	#[inline]
+	pub const fn as_str(&self) -> &str {
+		self.buf.as_str()
+	}
+
+	/// Returns the buffer if it holds exactly one complete JSON value.
+	pub const fn finish(self) -> Result<ConstStrBuf<CAP>, JsonError> {
+		match self.error {
+			Some(e) => Err(e),
+			None if self.depth != 0 => Err(JsonError::Incomplete),
+			None => match self.state {
+				JsonState::AfterValue => Ok(self.buf),
+				_ => Err(JsonError::Incomplete),
+			},
+		}
+	}
+
+	/// Opens an object (`{`).
+	pub const fn begin_object(&mut self) -> &mut Self {
+		self.begin(true)
+	}
+
+	/// Opens an array (`[`).
+	pub const fn begin_array(&mut self) -> &mut Self {
+		self.begin(false)
+	}
+
+	/// Closes the innermost object or array.
+	pub const fn end(&mut self) -> &mut Self {
+		if self.error.is_some() {
+			return self;
+		}
+		if self.depth == 0 {
+			return self.fail(JsonError::UnbalancedEnd);
+		}
+		if let JsonState::AfterKey = self.state {
+			return self.fail(JsonError::MissingValue);
+		}
+
+		self.write(if self.containers & 1 == 1 { "}" } else { "]" });
+		self.containers >>= 1;
+		self.depth -= 1;
+		self.state = JsonState::AfterValue;
+
+		self
+	}
+
+	/// Writes an object key; a value must follow.
+	pub const fn key(&mut self, key: &str) -> &mut Self {
+		if self.error.is_some() {
+			return self;
+		}
+		let in_object = self.depth != 0 && self.containers & 1 == 1;
+		match self.state {
+			_ if !in_object => return self.fail(JsonError::UnexpectedKey),
+			JsonState::AfterKey => return self.fail(JsonError::UnexpectedKey),
+			JsonState::AfterValue => self.write(","),
+			JsonState::Empty => {}
+		}
+
+		self.write_quoted(key);
+		self.write(":");
+		self.state = JsonState::AfterKey;
+
+		self
+	}
+
+	/// Writes an escaped string value.
+	pub const fn value_str(&mut self, value: &str) -> &mut Self {
+		if self.begin_value() {
+			self.write_quoted(value);
+			self.state = JsonState::AfterValue;
+		}
+
+		self
+	}
+
+	/// Writes an unsigned integer value.
+	pub const fn value_u64(&mut self, value: u64) -> &mut Self {
+		if self.begin_value() {
+			if self.error.is_none() && self.buf._try_push_u64(value).is_err() {
+				self.error = Some(JsonError::Overflow);
+			}
+			self.state = JsonState::AfterValue;
+		}
+
+		self
+	}
+
+	/// Writes `true` or `false`.
+	pub const fn value_bool(&mut self, value: bool) -> &mut Self {
+		if self.begin_value() {
+			self.write(if value { "true" } else { "false" });
+			self.state = JsonState::AfterValue;
+		}
+
+		self
+	}
+
+	/// Writes `null`.
+	pub const fn null(&mut self) -> &mut Self {
+		if self.begin_value() {
+			self.write("null");
+			self.state = JsonState::AfterValue;
+		}
+
+		self
+	}
+
+	const fn begin(&mut self, object: bool) -> &mut Self {
+		if !self.begin_value() {
+			return self;
+		}
+		if self.depth == MAX_DEPTH {
+			return self.fail(JsonError::TooDeep);
+		}
+
+		self.write(if object { "{" } else { "[" });
+		self.containers = (self.containers << 1) | object as u64;
+		self.depth += 1;
+		self.state = JsonState::Empty;
+
+		self
+	}
+
+	/// Checks that a value may be written here and writes the preceding comma.
+	const fn begin_value(&mut self) -> bool {
+		if self.error.is_some() {
+			return false;
+		}
+
+		let error = match (self.depth, self.state) {
+			(0, JsonState::Empty) => None,
+			(0, _) => Some(JsonError::MultipleRoots),
+			_ if self.containers & 1 == 1 => match self.state {
+				JsonState::AfterKey => None,
+				_ => Some(JsonError::ExpectedKey),
+			},
+			(_, JsonState::AfterValue) => {
+				self.write(",");
+				None
+			}
+			_ => None,
+		};
+		if let Some(e) = error {
+			self.fail(e);
+			return false;
+		}
+
+		true
+	}
+
+	const fn fail(&mut self, error: JsonError) -> &mut Self {
+		if self.error.is_none() {
+			self.error = Some(error);
+		}
+
+		self
+	}
+
+	const fn write(&mut self, s: &str) {
+		if self.error.is_none() && self.buf.try_push_str(s).is_err() {
+			self.error = Some(JsonError::Overflow);
+		}
+	}
+
+	const fn write_quoted(&mut self, s: &str) {
+		if self.error.is_none() && self.buf.try_push_quoted(s, Escape::Json).is_err() {
+			self.error = Some(JsonError::Overflow);
+		}
+	}
+}
@@ -1,14 +1,62 @@
 //! Compile-time buffer builder with UTF-8 safety and decimal formatting.
 //!
 
+mod array;
+mod base64;
+mod bin;
+mod chars;
+mod csv;
+mod edit;
+mod escape;
+mod hex;
+mod json;
+mod net;
+mod path;
+mod placeholder;
+mod reader;
+mod scratch;
+mod semver;
 pub mod size;
-
+mod time;
+mod url;
+mod utf8;
+mod uuid;
+
+pub use crate::buf::array::IntoArrayError;
+pub use crate::buf::array::LenPrefix;
+pub use crate::buf::csv::ConstCsvWriter;
+pub use crate::buf::csv::CsvLineEnding;
+pub use crate::buf::escape::Escape;
+pub use crate::buf::hex::HexCase;
+pub use crate::buf::json::ConstJsonWriter;
+pub use crate::buf::json::JsonError;
+pub use crate::buf::net::MacStyle;
+pub use crate::buf::path::PathError;
+pub use crate::buf::placeholder::LenPrefixMark;
+pub use crate::buf::placeholder::LenTooLarge;
+pub use crate::buf::placeholder::Placeholder;
+pub use crate::buf::reader::ConstByteReader;
+pub use crate::buf::reader::ReadError;
 use crate::buf::size::ConstByteBufSize;
+pub use crate::buf::time::TimestampStyle;
+pub use crate::buf::url::UrlEncodeSet;
+pub use crate::buf::url::UrlError;
+pub use crate::buf::url::UrlParts;
+pub use crate::buf::uuid::UuidStyle;
+use core::borrow::Borrow;
+use core::borrow::BorrowMut;
+use core::cmp::Ordering;
+use core::ffi::CStr;
+use core::ffi::FromBytesWithNulError;
 use core::fmt::Debug;
 use core::fmt::Display;
+use core::fmt::Write;
 use core::hash::Hash;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::str::FromStr;
 
 /// UTF-8 safe const buffer builder.
 ///
@@ -26,6 +74,23 @@ use core::mem::MaybeUninit;
 /// Suitable for compile-time generation of valid strings.
 pub type ConstStrBuf<const CAP: usize> = ConstByteBuf<CAP, Utf8SafeBuf>;
 
+/// Const buffer builder that can be borrowed as a `&CStr` at any time.
+///
+/// One byte of `CAP` is reserved for the NUL terminator.
+///
+/// # Example
+/// ```rust
+/// use cluConstData::buf::ConstCStrBuf;
+/// const fn build_name() -> ConstCStrBuf<16> {
+///	let mut buf = ConstCStrBuf::<16>::new();
+///	buf.push_str("hello");
+///	buf
+/// }
+///
+/// assert_eq!(build_name().as_cstr(), c"hello");
+/// ```
+pub type ConstCStrBuf<const CAP: usize> = ConstByteBuf<CAP, NulTermBuf>;
+
 /// Fixed-capacity builder for `const` contexts.
 ///
 /// Allows appending strings, raw bytes, or `usize` in decimal form—all in `const fn`.
@@ -41,11 +106,23 @@ where
 }
 
 /// Marker trait for buffer behavior customization.
-pub trait ConstByteBufData {}
+pub trait ConstByteBufData {
+	/// Keep a NUL byte right after the written data at all times.
+	///
+	/// One byte of the capacity is reserved for the terminator.
+	const NUL_TERMINATED: bool = false;
+
+	/// Hash the data as `str` rather than as `[u8]`, matching a `Borrow<str>` impl.
+	///
+	/// Data that is not valid UTF-8 is still hashed as `[u8]`.
+	const HASH_AS_STR: bool = false;
+}
 
 /// Marker type enforcing UTF-8 validation.
 pub enum Utf8SafeBuf {}
-impl ConstByteBufData for Utf8SafeBuf {}
+impl ConstByteBufData for Utf8SafeBuf {
+	const HASH_AS_STR: bool = true;
+}
 
 /// Marker type allowing unrestricted raw byte access.
 ///
@@ -53,18 +130,46 @@ impl ConstByteBufData for Utf8SafeBuf {}
 pub enum DefBuf {}
 impl ConstByteBufData for DefBuf {}
 
+/// Marker type keeping a trailing NUL after the written data.
+///
+/// Grants access to `as_cstr`/`try_as_cstr`.
+pub enum NulTermBuf {}
+impl ConstByteBufData for NulTermBuf {
+	const NUL_TERMINATED: bool = true;
+}
+
 impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Number of bytes available for data (`CAP` minus the terminator, if any).
+	const DATA_CAP: usize = match TData::NUL_TERMINATED {
+		true => match CAP.checked_sub(1) {
+			Some(a) => a,
+			None => panic!("ConstByteBuf: NUL-terminated buffer requires CAP >= 1"),
+		},
+		false => CAP,
+	};
+
 	/// Creates a new empty buffer.
 	///
 	/// Initializes all memory to uninitialized (`MaybeUninit`),
 	/// with the write cursor set to `0`.
 	#[inline]
 	pub const fn new() -> Self {
-		Self {
+		let mut result = Self {
 			tdata: PhantomData,
 
 			buf: [MaybeUninit::uninit(); CAP],
 			wpos: 0,
+		};
+		result._sync_nul();
+
+		result
+	}
+
+	/// Restores the NUL terminator after the written data in `NulTermBuf` mode.
+	#[inline]
+	const fn _sync_nul(&mut self) {
+		if TData::NUL_TERMINATED {
+			self.buf[self.wpos].write(0);
 		}
 	}
 
@@ -79,63 +184,120 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 		}
 	}
 
+	/// Creates a buffer containing a copy of `s`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn from_str(s: &str) -> Self {
+		match Self::try_from_str(s) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Creates a buffer containing a copy of `s`.
+	pub const fn try_from_str(s: &str) -> Result<Self, StackOverflow> {
+		let mut result = Self::new();
+		match result.try_push_str(s) {
+			Ok(_) => Ok(result),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Reinterprets the buffer with a different marker type, keeping bytes and write position.
+	///
+	/// The caller is responsible for the invariants of `TNewData`.
+	#[inline]
+	const fn _cast<TNewData: ConstByteBufData>(self) -> ConstByteBuf<CAP, TNewData> {
+		ConstByteBuf {
+			tdata: PhantomData,
+
+			buf: self.buf,
+			wpos: self.wpos,
+		}
+	}
+
 	/// Removes and returns the last written byte.
 	const fn _pop(&mut self) -> Option<u8> {
 		match self.wpos {
 			0 => None,
-			pos => {
-				let result = core::mem::replace(&mut self.buf[pos], MaybeUninit::uninit());
-				self.wpos -= 1;
+			len => {
+				// `len - 1 < wpos`, so the byte was written
+				let result = unsafe { self.buf[len - 1].assume_init_read() };
+				self.wpos = len - 1;
+				self._sync_nul();
 
-				Some(unsafe { result.assume_init() })
+				Some(result)
 			}
 		}
 	}
 
 	/// Converts this `ConstStrBuf` into a fully initialized `[u8; CAP]` array,
 	/// filling remaining capacity with a custom trailing byte (`space`).
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
-	#[cfg(feature = "clufulltransmute")]
 	const fn _into_array(mut self, space: u8) -> (usize, [u8; CAP]) {
 		let len = self.len();
 		while self.__try_write_byte(space).is_ok() {} // utf-8 safe
 
-		// TODO WAIT https://github.com/rust-lang/rust/issues/96097 in stable
-		(len, unsafe {
-			cluFullTransmute::transmute_unchecked(self.buf as [MaybeUninit<u8>; CAP])
-		})
+		// the whole capacity is written at this point
+		(len, unsafe { crate::array_assume_init(self.buf) })
 	}
 
 	/// Resets write position to 0, retains buffer contents.
 	#[inline]
 	pub const fn clear(&mut self) {
 		self.wpos = 0;
+		self._sync_nul();
 	}
 
-	/// Total capacity in bytes.
+	/// Total capacity in bytes (without the terminator in `NulTermBuf` mode).
 	#[inline]
 	pub const fn capacity(&self) -> usize {
-		self.buf.len()
+		Self::DATA_CAP
 	}
 
-	/// Returns a mutable reference to the byte at the given position.
+	/// Returns a mutable reference to the written byte at the given position.
 	const fn _get_mut(&mut self, pos: usize) -> Option<&mut u8> {
-		if pos > self.wpos {
+		if pos >= self.wpos {
 			return None;
 		}
 
+		// `pos < wpos`, so the byte was written
 		unsafe { Some(self.buf[pos].assume_init_mut()) }
 	}
 
-	/// Returns an immutable reference to the byte at the given position.
+	/// Returns an immutable reference to the written byte at the given position.
 	pub const fn get(&self, pos: usize) -> Option<&u8> {
-		if pos > self.wpos {
+		if pos >= self.wpos {
 			return None;
 		}
 
+		// `pos < wpos`, so the byte was written
 		unsafe { Some(self.buf[pos].assume_init_ref()) }
 	}
 
+	/// Returns the written bytes in `start..end`.
+	pub const fn get_range(&self, start: usize, end: usize) -> Option<&[u8]> {
+		if start > end || end > self.wpos {
+			return None;
+		}
+
+		let (head, _) = self.as_bytes().split_at(end);
+		let (_, range) = head.split_at(start);
+		Some(range)
+	}
+
+	/// Returns the first written byte.
+	#[inline]
+	pub const fn first(&self) -> Option<&u8> {
+		self.as_bytes().first()
+	}
+
+	/// Returns the last written byte.
+	#[inline]
+	pub const fn last(&self) -> Option<&u8> {
+		self.as_bytes().last()
+	}
+
 	/// Number of bytes already written.
 	#[inline]
 	pub const fn len(&self) -> usize {
@@ -151,7 +313,7 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 	/// Available capacity.
 	#[inline]
 	pub const fn available(&self) -> usize {
-		CAP - self.wpos
+		Self::DATA_CAP - self.wpos
 	}
 
 	/// Returns a raw pointer to the slice's buffer.
@@ -207,7 +369,7 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 	/// Appends raw bytes without UTF-8 check. Panics on overflow.
 	const fn __try_write_bytes_unchecked(&mut self, data: &[u8]) -> Result<usize, StackOverflow> {
 		let datalen = data.len();
-		if self.wpos + datalen > CAP {
+		if self.wpos + datalen > Self::DATA_CAP {
 			return Err(StackOverflow);
 		}
 
@@ -217,6 +379,7 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 			i += 1;
 		}
 		self.wpos += datalen;
+		self._sync_nul();
 		Ok(datalen)
 	}
 
@@ -233,12 +396,13 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 	/// Appends byte.
 	const fn __try_write_byte(&mut self, data: u8) -> Result<usize, StackOverflow> {
 		let datalen = 1;
-		if self.wpos + datalen > CAP {
+		if self.wpos + datalen > Self::DATA_CAP {
 			return Err(StackOverflow);
 		}
 
 		self.buf[self.wpos].write(data);
 		self.wpos += datalen;
+		self._sync_nul();
 		Ok(datalen)
 	}
 
@@ -300,6 +464,23 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 		self.__try_write_bytes_unchecked(slice)
 	}
 
+	/// Appends decimal representation of `u64` (independent of the pointer width).
+	pub(crate) const fn _try_push_u64(&mut self, mut value: u64) -> Result<usize, StackOverflow> {
+		let mut arr = [0u8; u64::MAX_DECIMAL_LEN];
+		let mut i = arr.len();
+		loop {
+			i -= 1;
+			arr[i] = b'0' + (value % 10) as u8;
+			value /= 10;
+			if value == 0 {
+				break;
+			}
+		}
+
+		let (_, digits) = arr.split_at(i);
+		self.__try_write_bytes_unchecked(digits)
+	}
+
 	/// Appends decimal representation of `isize`.
 	///
 	/// Panics on overflow.
@@ -335,12 +516,68 @@ impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
 	#[cold]
 	#[track_caller]
 	#[inline(never)]
-	const fn cold_overflow_panic() -> ! {
+	pub(crate) const fn cold_overflow_panic() -> ! {
 		panic!("ConstByteBuf overflow: capacity exceeded");
 	}
+
+	/// Panics when a position or range lies outside the written data.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_out_of_bounds_panic() -> ! {
+		panic!("ConstByteBuf: position out of bounds");
+	}
+
+	/// Panics when bytes passed to a `ConstStrBuf` are not valid UTF-8.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_invalid_utf8_panic() -> ! {
+		panic!("ConstStrBuf: invalid UTF-8 sequence");
+	}
 }
 
 impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Creates a buffer from bytes, validating them as UTF-8.
+	///
+	/// Panics on overflow or invalid UTF-8.
+	#[track_caller]
+	pub const fn from_bytes(data: &[u8]) -> Self {
+		match Self::try_from_bytes(data) {
+			Ok(a) => a,
+			Err(FromBytesError::Overflow(_)) => Self::cold_overflow_panic(),
+			Err(FromBytesError::Utf8(_)) => Self::cold_invalid_utf8_panic(),
+		}
+	}
+
+	/// Creates a buffer from bytes, validating them as UTF-8.
+	pub const fn try_from_bytes(data: &[u8]) -> Result<Self, FromBytesError> {
+		let str = match core::str::from_utf8(data) {
+			Ok(a) => a,
+			Err(e) => return Err(FromBytesError::Utf8(e)),
+		};
+
+		match Self::try_from_str(str) {
+			Ok(a) => Ok(a),
+			Err(e) => Err(FromBytesError::Overflow(e)),
+		}
+	}
+
+	/// Creates a buffer from an array, validating it as UTF-8.
+	///
+	/// Panics on overflow or invalid UTF-8.
+	#[track_caller]
+	#[inline]
+	pub const fn from_array<const N: usize>(array: [u8; N]) -> Self {
+		Self::from_bytes(&array)
+	}
+
+	/// Converts this `ConstStrBuf` into a raw byte buffer of the same capacity.
+	#[inline]
+	pub const fn into_bytes_buf(self) -> ConstByteBuf<CAP, DefBuf> {
+		self._cast() // any UTF-8 is valid raw data
+	}
+
 	/// Returns a slice of written bytes as a UTF-8 string.
 	#[inline]
 	pub const fn as_str(&self) -> &str {
@@ -413,6 +650,8 @@ impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
 
 	/// Removes and returns the last written byte.
 	///
+	/// See [`Self::pop_char`] for a UTF-8-safe alternative.
+	///
 	/// # Safety
 	/// May break UTF-8
 	#[inline]
@@ -431,21 +670,17 @@ impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
 	}
 
 	/// Converts this `ConstStrBuf` into a fully initialized `[u8; CAP]` array,
-	/// filling remaining capacity with a custom trailing byte (0)
+	/// filling remaining capacity with NUL bytes (`b'\0'`)
 	/// and also returning its original length.
 	#[inline]
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
-	#[cfg(feature = "clufulltransmute")]
 	pub const fn into_array_filled_with_zero(self) -> (usize, [u8; CAP]) {
-		self._into_array(b' ') // utf-8 safe
+		self._into_array(b'\0') // utf-8 safe
 	}
 
 	/// Converts this `ConstStrBuf` into a fully initialized `[u8; CAP]` array,
 	/// filling remaining capacity with a custom trailing byte (b' ')
 	/// and also returning its original length.
 	#[inline]
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
-	#[cfg(feature = "clufulltransmute")]
 	pub const fn into_array_filled_with_space(self) -> (usize, [u8; CAP]) {
 		self._into_array(b' ') // utf-8 safe
 	}
@@ -457,14 +692,50 @@ impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
 	/// It's safe as long as you send `utf-8` sequences,
 	/// if you send non-`utf-8` sequences you just break the API.
 	#[inline]
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
-	#[cfg(feature = "clufulltransmute")]
 	pub const unsafe fn into_array(self, space: u8) -> (usize, [u8; CAP]) {
 		self._into_array(space)
 	}
 }
 
 impl<const CAP: usize> ConstByteBuf<CAP, DefBuf> {
+	/// Creates a buffer containing a copy of `data`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn from_bytes(data: &[u8]) -> Self {
+		match Self::try_from_bytes(data) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Creates a buffer containing a copy of `data`.
+	pub const fn try_from_bytes(data: &[u8]) -> Result<Self, StackOverflow> {
+		let mut result = Self::new();
+		match result.try_write_bytes(data) {
+			Ok(_) => Ok(result),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Creates a buffer containing a copy of `array`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	#[inline]
+	pub const fn from_array<const N: usize>(array: [u8; N]) -> Self {
+		Self::from_bytes(&array)
+	}
+
+	/// Converts this buffer into a `ConstStrBuf` of the same capacity,
+	/// validating the written bytes as UTF-8.
+	pub const fn try_into_str_buf(self) -> Result<ConstStrBuf<CAP>, core::str::Utf8Error> {
+		match core::str::from_utf8(self.as_bytes()) {
+			Ok(_) => Ok(self._cast()),
+			Err(e) => Err(e),
+		}
+	}
+
 	/// Appends raw bytes. Panics on overflow.
 	///
 	/// Panics on overflow.
@@ -518,23 +789,105 @@ impl<const CAP: usize> ConstByteBuf<CAP, DefBuf> {
 	/// filling remaining capacity with a custom trailing byte (space)
 	/// and also returning its original length.
 	#[inline]
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
-	#[cfg(feature = "clufulltransmute")]
 	pub const fn into_array(self, space: u8) -> (usize, [u8; CAP]) {
 		self._into_array(space) // utf-8 safe
 	}
 }
 
+impl<const CAP: usize> ConstByteBuf<CAP, NulTermBuf> {
+	/// Appends raw bytes.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	#[inline]
+	pub const fn write_bytes(&mut self, data: &[u8]) -> usize {
+		self.__write_bytes_unchecked(data)
+	}
+
+	/// Appends raw bytes.
+	#[inline]
+	pub const fn try_write_bytes(&mut self, data: &[u8]) -> Result<usize, StackOverflow> {
+		self.__try_write_bytes_unchecked(data)
+	}
+
+	/// Appends byte.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	#[inline]
+	pub const fn write_byte(&mut self, data: u8) -> usize {
+		self.__write_byte(data)
+	}
+
+	/// Appends byte.
+	#[inline]
+	pub const fn try_write_byte(&mut self, data: u8) -> Result<usize, StackOverflow> {
+		self.__try_write_byte(data)
+	}
+
+	/// Returns a mutable reference to the byte at the given position.
+	#[inline]
+	pub const fn get_mut(&mut self, pos: usize) -> Option<&mut u8> {
+		self._get_mut(pos)
+	}
+
+	/// Removes and returns the last written byte.
+	#[inline]
+	pub const fn pop(&mut self) -> Option<u8> {
+		self._pop()
+	}
+
+	/// Returns a mut slice of written bytes (the terminator is not included).
+	#[inline]
+	pub const fn as_mut_bytes(&mut self) -> &mut [u8] {
+		self._as_mut_bytes()
+	}
+
+	/// Returns written bytes including the trailing NUL.
+	#[inline]
+	pub const fn as_bytes_with_nul(&self) -> &[u8] {
+		// the terminator at `wpos` is always written in this mode
+		unsafe { core::slice::from_raw_parts(self.as_ptr() as *const u8, self.wpos + 1) }
+	}
+
+	/// Returns written bytes as a `&CStr`.
+	///
+	/// Panics if the data contains a NUL byte.
+	#[track_caller]
+	pub const fn as_cstr(&self) -> &CStr {
+		match self.try_as_cstr() {
+			Ok(a) => a,
+			Err(_) => Self::cold_interior_nul_panic(),
+		}
+	}
+
+	/// Returns written bytes as a `&CStr`, failing if the data contains a NUL byte.
+	#[inline]
+	pub const fn try_as_cstr(&self) -> Result<&CStr, FromBytesWithNulError> {
+		CStr::from_bytes_with_nul(self.as_bytes_with_nul())
+	}
+
+	/// Panics when the data of a `ConstCStrBuf` contains a NUL byte.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_interior_nul_panic() -> ! {
+		panic!("ConstCStrBuf: data contains an interior NUL byte");
+	}
+}
+
 impl<const CAP: usize, TData> Clone for ConstByteBuf<CAP, TData>
 where
 	TData: ConstByteBufData,
 {
 	#[inline]
 	fn clone(&self) -> Self {
-		ConstByteBuf::clone(self)
+		*self
 	}
 }
 
+impl<const CAP: usize, TData> Copy for ConstByteBuf<CAP, TData> where TData: ConstByteBufData {}
+
 impl<const CAP: usize, TData> Default for ConstByteBuf<CAP, TData>
 where
 	TData: ConstByteBufData,
@@ -552,6 +905,34 @@ impl<const CAP: usize> Display for ConstByteBuf<CAP, Utf8SafeBuf> {
 	}
 }
 
+impl<const CAP: usize> Display for ConstByteBuf<CAP, DefBuf> {
+	/// Lossy output: invalid UTF-8 sequences are replaced with `U+FFFD`.
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fmt_lossy(self.as_bytes(), f)
+	}
+}
+
+impl<const CAP: usize> Display for ConstByteBuf<CAP, NulTermBuf> {
+	/// Lossy output: invalid UTF-8 sequences are replaced with `U+FFFD`.
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		fmt_lossy(self.as_bytes(), f)
+	}
+}
+
+/// Writes `bytes` as text, replacing invalid UTF-8 sequences with `U+FFFD`.
+fn fmt_lossy(bytes: &[u8], f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+	for chunk in bytes.utf8_chunks() {
+		f.write_str(chunk.valid())?;
+		if !chunk.invalid().is_empty() {
+			f.write_char(char::REPLACEMENT_CHARACTER)?;
+		}
+	}
+
+	Ok(())
+}
+
 impl<const CAP: usize> Debug for ConstByteBuf<CAP, DefBuf> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_struct("ConstByteBuf")
@@ -561,6 +942,15 @@ impl<const CAP: usize> Debug for ConstByteBuf<CAP, DefBuf> {
 	}
 }
 
+impl<const CAP: usize> Debug for ConstByteBuf<CAP, NulTermBuf> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("ConstCStrBuf")
+			.field("buf", &self.as_bytes())
+			.field("wpos", &self.wpos)
+			.finish()
+	}
+}
+
 impl<const CAP: usize> Debug for ConstByteBuf<CAP, Utf8SafeBuf> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		f.debug_struct("ConstStrBuf")
@@ -626,18 +1016,342 @@ where
 	}
 }
 
+impl<const CAP: usize, TData> PartialOrd for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(Ord::cmp(self, other))
+	}
+}
+
+impl<const CAP: usize, TData> Ord for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	/// Lexicographic byte order (for `ConstStrBuf` it matches `str` ordering).
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		Ord::cmp(self.as_bytes(), other.as_bytes())
+	}
+}
+
+/// Hashes as `str` if [`ConstByteBufData::HASH_AS_STR`] is set, otherwise as `[u8]`,
+/// so that `Borrow` lookups agree with `Hash`.
 impl<const CAP: usize, TData> Hash for ConstByteBuf<CAP, TData>
 where
 	TData: ConstByteBufData,
 {
 	#[inline]
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		if TData::HASH_AS_STR
+			&& let Ok(s) = core::str::from_utf8(self.as_bytes())
+		{
+			return Hash::hash(s, state);
+		}
+
 		Hash::hash(self.as_bytes(), state)
 	}
 }
 
+impl<const CAP: usize> Deref for ConstByteBuf<CAP, Utf8SafeBuf> {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_str()
+	}
+}
+
+impl<const CAP: usize> DerefMut for ConstByteBuf<CAP, Utf8SafeBuf> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_str()
+	}
+}
+
+impl<const CAP: usize> Deref for ConstByteBuf<CAP, DefBuf> {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bytes()
+	}
+}
+
+impl<const CAP: usize> DerefMut for ConstByteBuf<CAP, DefBuf> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bytes()
+	}
+}
+
+impl<const CAP: usize> AsRef<str> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	#[inline]
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl<const CAP: usize> AsMut<str> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut str {
+		self.as_mut_str()
+	}
+}
+
+impl<const CAP: usize, TData> AsRef<[u8]> for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl<const CAP: usize> AsMut<[u8]> for ConstByteBuf<CAP, DefBuf> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [u8] {
+		self.as_mut_bytes()
+	}
+}
+
+impl<const CAP: usize> Borrow<str> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	#[inline]
+	fn borrow(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl<const CAP: usize> BorrowMut<str> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut str {
+		self.as_mut_str()
+	}
+}
+
+impl<const CAP: usize> Borrow<[u8]> for ConstByteBuf<CAP, DefBuf> {
+	#[inline]
+	fn borrow(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl<const CAP: usize> BorrowMut<[u8]> for ConstByteBuf<CAP, DefBuf> {
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut [u8] {
+		self.as_mut_bytes()
+	}
+}
+
+/// Panics on overflow.
+impl<const CAP: usize, TData> Extend<char> for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+		for c in iter {
+			self.push_char(c);
+		}
+	}
+}
+
+/// Panics on overflow.
+impl<'a, const CAP: usize, TData> Extend<&'a char> for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+		for c in iter {
+			self.push_char(*c);
+		}
+	}
+}
+
+/// Panics on overflow.
+impl<'a, const CAP: usize, TData> Extend<&'a str> for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+		for s in iter {
+			self.push_str(s);
+		}
+	}
+}
+
+/// Generates the raw byte `Extend` implementations.
+///
+/// Panics on overflow.
+macro_rules! impl_extend_bytes {
+	[ $($tdata:ident),* $(,)? ] => {
+		$(
+			impl<const CAP: usize> Extend<u8> for ConstByteBuf<CAP, $tdata> {
+				fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+					for b in iter {
+						self.write_byte(b);
+					}
+				}
+			}
+
+			impl<'a, const CAP: usize> Extend<&'a u8> for ConstByteBuf<CAP, $tdata> {
+				fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+					for b in iter {
+						self.write_byte(*b);
+					}
+				}
+			}
+
+			impl<'a, const CAP: usize> Extend<&'a [u8]> for ConstByteBuf<CAP, $tdata> {
+				fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+					for data in iter {
+						self.write_bytes(data);
+					}
+				}
+			}
+		)*
+	};
+}
+
+impl_extend_bytes! {
+	DefBuf,
+	NulTermBuf,
+}
+
+/// Generates `FromIterator` on top of the corresponding `Extend` implementation.
+///
+/// Panics on overflow.
+macro_rules! impl_from_iterator {
+	[
+		$(
+			impl<$($l:lifetime)?> FromIterator<$item:ty> for $tdata:ident;
+		)*
+	] => {
+		$(
+			impl<$($l,)? const CAP: usize> FromIterator<$item> for ConstByteBuf<CAP, $tdata> {
+				#[inline]
+				fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+					let mut buf = Self::new();
+					buf.extend(iter);
+					buf
+				}
+			}
+		)*
+	};
+}
+
+impl_from_iterator! {
+	impl<> FromIterator<char> for Utf8SafeBuf;
+	impl<'a> FromIterator<&'a char> for Utf8SafeBuf;
+	impl<'a> FromIterator<&'a str> for Utf8SafeBuf;
+
+	impl<> FromIterator<char> for DefBuf;
+	impl<'a> FromIterator<&'a char> for DefBuf;
+	impl<'a> FromIterator<&'a str> for DefBuf;
+	impl<> FromIterator<u8> for DefBuf;
+	impl<'a> FromIterator<&'a u8> for DefBuf;
+	impl<'a> FromIterator<&'a [u8]> for DefBuf;
+
+	impl<> FromIterator<char> for NulTermBuf;
+	impl<'a> FromIterator<&'a char> for NulTermBuf;
+	impl<'a> FromIterator<&'a str> for NulTermBuf;
+	impl<> FromIterator<u8> for NulTermBuf;
+	impl<'a> FromIterator<&'a u8> for NulTermBuf;
+	impl<'a> FromIterator<&'a [u8]> for NulTermBuf;
+}
+
+impl<const CAP: usize, TData> TryFrom<&'_ str> for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	type Error = StackOverflow;
+
+	#[inline]
+	fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
+		Self::try_from_str(value)
+	}
+}
+
+impl<const CAP: usize> TryFrom<&'_ [u8]> for ConstByteBuf<CAP, DefBuf> {
+	type Error = StackOverflow;
+
+	#[inline]
+	fn try_from(value: &'_ [u8]) -> Result<Self, Self::Error> {
+		Self::try_from_bytes(value)
+	}
+}
+
+impl<const CAP: usize> TryFrom<&'_ [u8]> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	type Error = FromBytesError;
+
+	#[inline]
+	fn try_from(value: &'_ [u8]) -> Result<Self, Self::Error> {
+		Self::try_from_bytes(value)
+	}
+}
+
+impl<const CAP: usize> From<ConstByteBuf<CAP, Utf8SafeBuf>> for ConstByteBuf<CAP, DefBuf> {
+	#[inline]
+	fn from(value: ConstByteBuf<CAP, Utf8SafeBuf>) -> Self {
+		value.into_bytes_buf()
+	}
+}
+
+impl<const CAP: usize> TryFrom<ConstByteBuf<CAP, DefBuf>> for ConstByteBuf<CAP, Utf8SafeBuf> {
+	type Error = core::str::Utf8Error;
+
+	#[inline]
+	fn try_from(value: ConstByteBuf<CAP, DefBuf>) -> Result<Self, Self::Error> {
+		value.try_into_str_buf()
+	}
+}
+
+impl<const CAP: usize, TData> FromStr for ConstByteBuf<CAP, TData>
+where
+	TData: ConstByteBufData,
+{
+	type Err = StackOverflow;
+
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::try_from_str(s)
+	}
+}
+
 /// Error type indicating buffer overflow during write.
 ///
 /// Returned when a write operation exceeds the fixed capacity of a `ConstByteBuf`.
 #[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct StackOverflow;
+
+impl Display for StackOverflow {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("ConstByteBuf overflow: capacity exceeded")
+	}
+}
+
+impl core::error::Error for StackOverflow {}
+
+/// Error type returned when creating a `ConstStrBuf` from raw bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+	/// The data does not fit into the buffer.
+	Overflow(StackOverflow),
+	/// The data is not valid UTF-8.
+	Utf8(core::str::Utf8Error),
+}
+
+impl Display for FromBytesError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::Overflow(e) => Display::fmt(e, f),
+			Self::Utf8(e) => Display::fmt(e, f),
+		}
+	}
+}
+
+impl core::error::Error for FromBytesError {}
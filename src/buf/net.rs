@@ -0,0 +1,231 @@
+//! Const formatting of IP, socket and MAC addresses.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use crate::buf::StackOverflow;
+use crate::buf::scratch::Scratch;
+use crate::buf::size::ConstByteBufDisplaySize;
+use core::net::IpAddr;
+use core::net::Ipv4Addr;
+use core::net::Ipv6Addr;
+use core::net::SocketAddr;
+
+/// Separator style of [`ConstByteBuf::push_mac`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MacStyle {
+	/// `00:1a:2b:3c:4d:5e`
+	Colon,
+	/// `00-1a-2b-3c-4d-5e`
+	Dash,
+}
+
+impl MacStyle {
+	/// Number of bytes of a formatted MAC address.
+	pub const LEN: usize = 6 * 3 - 1;
+}
+
+/// Scratch space for the longest formatted address.
+type AddrScratch = Scratch<{ SocketAddr::MAX_DISPLAY_LEN }>;
+
+const fn write_ipv4(out: &mut AddrScratch, addr: Ipv4Addr) {
+	let octets = addr.octets();
+	let mut i = 0;
+	while i < octets.len() {
+		if i > 0 {
+			out.push_byte(b'.');
+		}
+		out.push_decimal(octets[i] as u64, 1);
+		i += 1;
+	}
+}
+
+/// Writes an IPv6 address as recommended by RFC 5952.
+const fn write_ipv6(out: &mut AddrScratch, addr: Ipv6Addr) {
+	if let Some(ipv4) = addr.to_ipv4_mapped() {
+		out.push(b"::ffff:");
+		write_ipv4(out, ipv4);
+		return;
+	}
+
+	// the first longest run of at least two zero groups is compressed
+	let segments = addr.segments();
+	let (mut zeros_start, mut zeros_len) = (0, 0);
+	let mut i = 0;
+	while i < segments.len() {
+		let start = i;
+		while i < segments.len() && segments[i] == 0 {
+			i += 1;
+		}
+		if i - start > zeros_len && i - start >= 2 {
+			(zeros_start, zeros_len) = (start, i - start);
+		}
+		i += 1;
+	}
+
+	let mut i = 0;
+	while i < segments.len() {
+		if zeros_len != 0 && i == zeros_start {
+			out.push(b"::");
+			i += zeros_len;
+			continue;
+		}
+		if i > 0 && !(zeros_len != 0 && i == zeros_start + zeros_len) {
+			out.push_byte(b':');
+		}
+		out.push_hex(segments[i] as u64);
+		i += 1;
+	}
+}
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Appends an IPv4 address in dotted-decimal notation.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_ipv4(&mut self, addr: Ipv4Addr) -> usize {
+		match self.try_push_ipv4(addr) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends an IPv4 address in dotted-decimal notation.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_ipv4(&mut self, addr: Ipv4Addr) -> Result<usize, StackOverflow> {
+		let mut out = AddrScratch::new();
+		write_ipv4(&mut out, addr);
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+
+	/// Appends an IPv6 address with RFC 5952 zero compression (`2001:db8::1`).
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::ConstStrBuf;
+	/// use cluConstData::buf::size::ConstByteBufDisplaySize;
+	/// use core::net::Ipv6Addr;
+	///
+	/// const ADDR: ConstStrBuf<{ Ipv6Addr::MAX_DISPLAY_LEN }> = {
+	///	let mut buf = ConstStrBuf::new();
+	///	buf.push_ipv6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1));
+	///	buf
+	/// };
+	/// assert_eq!(ADDR, "2001:db8::1:0:0:1");
+	/// ```
+	#[track_caller]
+	pub const fn push_ipv6(&mut self, addr: Ipv6Addr) -> usize {
+		match self.try_push_ipv6(addr) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends an IPv6 address with RFC 5952 zero compression (`2001:db8::1`).
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_ipv6(&mut self, addr: Ipv6Addr) -> Result<usize, StackOverflow> {
+		let mut out = AddrScratch::new();
+		write_ipv6(&mut out, addr);
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+
+	/// Appends an IPv4 or IPv6 address.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_ip(&mut self, addr: IpAddr) -> usize {
+		match self.try_push_ip(addr) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends an IPv4 or IPv6 address.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_ip(&mut self, addr: IpAddr) -> Result<usize, StackOverflow> {
+		match addr {
+			IpAddr::V4(a) => self.try_push_ipv4(a),
+			IpAddr::V6(a) => self.try_push_ipv6(a),
+		}
+	}
+
+	/// Appends a socket address: `10.0.0.1:80` or `[2001:db8::1%2]:443`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_socket_addr(&mut self, addr: SocketAddr) -> usize {
+		match self.try_push_socket_addr(addr) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a socket address: `10.0.0.1:80` or `[2001:db8::1%2]:443`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_socket_addr(&mut self, addr: SocketAddr) -> Result<usize, StackOverflow> {
+		let mut out = AddrScratch::new();
+		match addr {
+			SocketAddr::V4(a) => write_ipv4(&mut out, *a.ip()),
+			SocketAddr::V6(a) => {
+				out.push_byte(b'[');
+				write_ipv6(&mut out, *a.ip());
+				if a.scope_id() != 0 {
+					out.push_byte(b'%');
+					out.push_decimal(a.scope_id() as u64, 1);
+				}
+				out.push_byte(b']');
+			}
+		}
+		out.push_byte(b':');
+		out.push_decimal(addr.port() as u64, 1);
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+
+	/// Appends a MAC address as lowercase hex pairs separated according to `style`.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_mac(&mut self, mac: &[u8; 6], style: MacStyle) -> usize {
+		match self.try_push_mac(mac, style) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a MAC address as lowercase hex pairs separated according to `style`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_mac(
+		&mut self,
+		mac: &[u8; 6],
+		style: MacStyle,
+	) -> Result<usize, StackOverflow> {
+		let separator = match style {
+			MacStyle::Colon => b':',
+			MacStyle::Dash => b'-',
+		};
+
+		let mut out = Scratch::<{ MacStyle::LEN }>::new();
+		let mut i = 0;
+		while i < mac.len() {
+			if i > 0 {
+				out.push_byte(separator);
+			}
+			if mac[i] < 0x10 {
+				out.push_byte(b'0');
+			}
+			out.push_hex(mac[i] as u64);
+			i += 1;
+		}
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+}
@@ -0,0 +1,196 @@
+//! Const joining and normalization of `/`-separated paths.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::Utf8SafeBuf;
+use core::fmt::Display;
+
+/// Error type returned by [`ConstStrBuf::try_push_path_segment`](crate::buf::ConstStrBuf::try_push_path_segment).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathError {
+	/// A `..` component would go above the root (or the start of a relative path).
+	EscapesRoot,
+	/// The buffer has not enough space left.
+	Overflow,
+}
+
+impl PathError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::EscapesRoot => "invalid path: `..` escapes above the root",
+			Self::Overflow => "ConstByteBuf overflow: capacity exceeded",
+		}
+	}
+}
+
+impl Display for PathError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for PathError {}
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Appends `segment` to the path in the buffer.
+	///
+	/// Exactly one `/` is inserted between components, empty and `.` components are
+	/// skipped and `..` removes the previous component. A leading `/` makes an empty
+	/// buffer an absolute path. The buffer is expected to already hold a normalized path.
+	///
+	/// Returns the number of bytes written; bytes removed by `..` are not subtracted.
+	///
+	/// Panics if `..` escapes above the root or on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::ConstStrBuf;
+	///
+	/// let mut buf = ConstStrBuf::<32>::new();
+	/// buf.push_path_segment("/etc/");
+	/// buf.push_path_segment("//app/./tmp/..");
+	/// buf.push_path_segment("config.toml");
+	/// assert_eq!(buf, "/etc/app/config.toml");
+	/// ```
+	#[track_caller]
+	pub const fn push_path_segment(&mut self, segment: &str) -> usize {
+		match self.try_push_path_segment(segment) {
+			Ok(a) => a,
+			Err(PathError::Overflow) => Self::cold_overflow_panic(),
+			Err(e) => panic!("{}", e.as_str()),
+		}
+	}
+
+	/// Appends `segment` to the path in the buffer, see [`Self::push_path_segment`].
+	///
+	/// The buffer is left unchanged on error.
+	pub const fn try_push_path_segment(&mut self, segment: &str) -> Result<usize, PathError> {
+		let backup = *self;
+		let s = segment.as_bytes();
+		let mut written = 0;
+		if self.is_empty() && !s.is_empty() && s[0] == b'/' {
+			let _ = self.__try_write_byte(b'/'); // an empty buffer cannot overflow
+			written += 1;
+		}
+
+		let mut start = 0;
+		while start < s.len() {
+			let mut end = start;
+			while end < s.len() && s[end] != b'/' {
+				end += 1;
+			}
+			let (_, rest) = s.split_at(start);
+			let (component, _) = rest.split_at(end - start);
+
+			let result = match component {
+				b"" | b"." => Ok(0),
+				b".." => match self.pop_path_component() {
+					Ok(()) => Ok(0),
+					Err(e) => Err(e),
+				},
+				_ => self.push_path_component(component),
+			};
+			match result {
+				Ok(a) => written += a,
+				Err(e) => {
+					*self = backup;
+					return Err(e);
+				}
+			}
+			start = end + 1;
+		}
+
+		Ok(written)
+	}
+
+	/// Removes the last component, keeping the root `/`.
+	const fn pop_path_component(&mut self) -> Result<(), PathError> {
+		let bytes = self.as_bytes();
+		let mut i = bytes.len();
+		while i > 0 && bytes[i - 1] != b'/' {
+			i -= 1;
+		}
+
+		match i {
+			_ if i == bytes.len() => Err(PathError::EscapesRoot), // empty or the root
+			0 => {
+				self.clear();
+				Ok(())
+			}
+			1 => {
+				self.truncate(1);
+				Ok(())
+			}
+			_ => {
+				self.truncate(i - 1);
+				Ok(())
+			}
+		}
+	}
+
+	/// Appends a component, preceded by `/` unless the buffer is empty or ends with one.
+	const fn push_path_component(&mut self, component: &[u8]) -> Result<usize, PathError> {
+		let separator = match self.last() {
+			Some(b'/') | None => 0,
+			Some(_) => 1,
+		};
+		if separator + component.len() > self.available() {
+			return Err(PathError::Overflow);
+		}
+
+		// cannot fail: the length was checked above
+		if separator == 1 {
+			let _ = self.__try_write_byte(b'/');
+		}
+		let _ = self.__try_write_bytes_unchecked(component); // split at ASCII `/`, still UTF-8
+
+		Ok(separator + component.len())
+	}
+}
+
+/// Compile-time joined and normalized path as `&'static str`.
+///
+/// Segments are joined with exactly one `/`; duplicate slashes, `.` and `..` are
+/// resolved as in [`ConstStrBuf::push_path_segment`](crate::buf::ConstStrBuf::push_path_segment).
+/// Escaping above the root is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::concat_path;
+///
+/// const APP: &str = "my_app/";
+/// const CONFIG: &str = concat_path!("/etc", APP, "/config.toml");
+/// const CACHE: &str = concat_path!("/var/cache/", APP, "../shared", ".");
+///
+/// assert_eq!(CONFIG, "/etc/my_app/config.toml");
+/// assert_eq!(CACHE, "/var/cache/shared");
+/// ```
+///
+/// ```rust,compile_fail
+/// const PATH: &str = cluConstData::concat_path!("/etc", "../..");
+/// ```
+#[macro_export]
+macro_rules! concat_path {
+	[$($segment: expr),+ $(,)?] => {{
+		const _SEGMENTS: &[&str] = &[$($segment),+];
+		const _PATH: $crate::buf::ConstStrBuf<{ $crate::buf::size::path_max_len(_SEGMENTS) }> = {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			let mut i = 0;
+			while i < _SEGMENTS.len() {
+				if let Err(e) = buf.try_push_path_segment(_SEGMENTS[i]) {
+					panic!("{}", e.as_str());
+				}
+				i += 1;
+			}
+
+			buf
+		};
+
+		$crate::__buf_str!(_PATH.len(), {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_str(_PATH.as_str());
+			buf
+		})
+	}};
+}
@@ -0,0 +1,224 @@
+//! Reserve-and-backfill placeholders for length-prefixed data.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::DefBuf;
+use crate::buf::LenPrefix;
+use crate::buf::StackOverflow;
+use core::fmt::Display;
+
+/// Reserved `N`-byte region of a `ConstByteBuf`, filled later with `fill_*`.
+///
+/// A placeholder is only meaningful for the buffer that created it.
+#[must_use]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Placeholder<const N: usize> {
+	pos: usize,
+}
+
+impl<const N: usize> Placeholder<N> {
+	/// Position of the reserved region in the buffer.
+	#[inline]
+	pub const fn pos(&self) -> usize {
+		self.pos
+	}
+}
+
+/// Open length prefix created by `begin_len_prefix`, closed by `end_len_prefix`.
+#[must_use]
+#[derive(Debug, PartialEq, Eq)]
+pub struct LenPrefixMark {
+	pos: usize,
+	prefix: LenPrefix,
+}
+
+/// Generates `fill_*` methods writing a value into a placeholder of matching width.
+macro_rules! impl_fill_bin {
+	[
+		$(
+			$(#[$meta:meta])*
+			$name:ident: $ty:ty => $to_bytes:ident;
+		)*
+	] => {
+		$(
+			$(#[$meta])*
+			///
+			/// Panics if the placeholder lies outside the written data.
+			#[track_caller]
+			#[inline]
+			pub const fn $name(
+				&mut self,
+				placeholder: Placeholder<{ size_of::<$ty>() }>,
+				value: $ty,
+			) {
+				self.fill(placeholder, value.$to_bytes())
+			}
+		)*
+	};
+}
+
+impl<const CAP: usize> ConstByteBuf<CAP, DefBuf> {
+	/// Reserves `N` zero bytes to be filled later.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn reserve<const N: usize>(&mut self) -> Placeholder<N> {
+		match self.try_reserve() {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Reserves `N` zero bytes to be filled later.
+	pub const fn try_reserve<const N: usize>(&mut self) -> Result<Placeholder<N>, StackOverflow> {
+		let pos = self.wpos;
+		match self.try_write_bytes(&[0; N]) {
+			Ok(_) => Ok(Placeholder { pos }),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Writes `data` into a reserved region.
+	///
+	/// Panics if the placeholder lies outside the written data.
+	#[track_caller]
+	pub const fn fill<const N: usize>(&mut self, placeholder: Placeholder<N>, data: [u8; N]) {
+		let pos = placeholder.pos;
+		if pos + N > self.wpos {
+			Self::cold_out_of_bounds_panic();
+		}
+
+		let mut i = 0;
+		while i < N {
+			self.buf[pos + i].write(data[i]);
+			i += 1;
+		}
+	}
+
+	impl_fill_bin! {
+		/// Writes `u8` into a reserved region.
+		fill_u8: u8 => to_le_bytes;
+
+		/// Writes `u16` in little-endian byte order into a reserved region.
+		fill_u16_le: u16 => to_le_bytes;
+		/// Writes `u16` in big-endian byte order into a reserved region.
+		fill_u16_be: u16 => to_be_bytes;
+		/// Writes `u32` in little-endian byte order into a reserved region.
+		fill_u32_le: u32 => to_le_bytes;
+		/// Writes `u32` in big-endian byte order into a reserved region.
+		fill_u32_be: u32 => to_be_bytes;
+		/// Writes `u64` in little-endian byte order into a reserved region.
+		fill_u64_le: u64 => to_le_bytes;
+		/// Writes `u64` in big-endian byte order into a reserved region.
+		fill_u64_be: u64 => to_be_bytes;
+
+		/// Writes `i8` into a reserved region.
+		fill_i8: i8 => to_le_bytes;
+
+		/// Writes `i16` in little-endian byte order into a reserved region.
+		fill_i16_le: i16 => to_le_bytes;
+		/// Writes `i16` in big-endian byte order into a reserved region.
+		fill_i16_be: i16 => to_be_bytes;
+		/// Writes `i32` in little-endian byte order into a reserved region.
+		fill_i32_le: i32 => to_le_bytes;
+		/// Writes `i32` in big-endian byte order into a reserved region.
+		fill_i32_be: i32 => to_be_bytes;
+		/// Writes `i64` in little-endian byte order into a reserved region.
+		fill_i64_le: i64 => to_le_bytes;
+		/// Writes `i64` in big-endian byte order into a reserved region.
+		fill_i64_be: i64 => to_be_bytes;
+	}
+
+	/// Reserves room for a length prefix; everything written until
+	/// `end_len_prefix` becomes the prefixed body.
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn begin_len_prefix(&mut self, prefix: LenPrefix) -> LenPrefixMark {
+		match self.try_begin_len_prefix(prefix) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Reserves room for a length prefix; everything written until
+	/// `end_len_prefix` becomes the prefixed body.
+	pub const fn try_begin_len_prefix(
+		&mut self,
+		prefix: LenPrefix,
+	) -> Result<LenPrefixMark, StackOverflow> {
+		let pos = self.wpos;
+		let (zeroes, _) = [0; 4].split_at(prefix.width());
+		match self.try_write_bytes(zeroes) {
+			Ok(_) => Ok(LenPrefixMark { pos, prefix }),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Writes the length of the body written since `begin_len_prefix`,
+	/// returning that length.
+	///
+	/// Panics if the body does not fit into the prefix or the mark lies outside the written data.
+	#[track_caller]
+	pub const fn end_len_prefix(&mut self, mark: LenPrefixMark) -> usize {
+		match self.try_end_len_prefix(mark) {
+			Ok(a) => a,
+			Err(_) => Self::cold_len_too_large_panic(),
+		}
+	}
+
+	/// Writes the length of the body written since `begin_len_prefix`,
+	/// returning that length.
+	///
+	/// Panics if the mark lies outside the written data.
+	#[track_caller]
+	pub const fn try_end_len_prefix(&mut self, mark: LenPrefixMark) -> Result<usize, LenTooLarge> {
+		let width = mark.prefix.width();
+		if mark.pos + width > self.wpos {
+			Self::cold_out_of_bounds_panic();
+		}
+
+		let len = self.wpos - mark.pos - width;
+		if len > mark.prefix.max_len() {
+			return Err(LenTooLarge);
+		}
+
+		let (_, tail) = self._as_mut_bytes().split_at_mut(mark.pos);
+		mark.prefix.encode(len, tail);
+
+		Ok(len)
+	}
+
+	/// Writes a length-prefixed body produced by `f` (not usable in `const fn`,
+	/// see `begin_len_prefix`/`end_len_prefix`), returning the body length.
+	///
+	/// Panics on overflow or if the body does not fit into the prefix.
+	#[track_caller]
+	pub fn with_len_prefix(&mut self, prefix: LenPrefix, f: impl FnOnce(&mut Self)) -> usize {
+		let mark = self.begin_len_prefix(prefix);
+		f(self);
+
+		self.end_len_prefix(mark)
+	}
+
+	/// Panics when a body does not fit into its length prefix.
+	#[cold]
+	#[track_caller]
+	#[inline(never)]
+	const fn cold_len_too_large_panic() -> ! {
+		panic!("ConstByteBuf: length does not fit into the prefix");
+	}
+}
+
+/// Error type indicating that a body is too long for its length prefix.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LenTooLarge;
+
+impl Display for LenTooLarge {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("length does not fit into the prefix")
+	}
+}
+
+impl core::error::Error for LenTooLarge {}
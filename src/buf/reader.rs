@@ -0,0 +1,300 @@
+//! Const cursor for reading back binary data.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use core::fmt::Display;
+
+/// Const cursor over a byte slice.
+///
+/// Every `read_*` method either consumes the value and advances the cursor,
+/// or returns an error and leaves the cursor untouched.
+///
+/// # Example
+/// ```rust
+/// use cluConstData::buf::{ConstByteBuf, ConstByteReader};
+///
+/// const BLOB: ConstByteBuf<8> = {
+///	let mut buf = ConstByteBuf::<8>::new();
+///	buf.push_u16_le(0x0102);
+///	buf.push_str("ok");
+///	buf
+/// };
+///
+/// const _: () = {
+///	let mut reader = BLOB.reader();
+///	assert!(matches!(reader.read_u16_le(), Ok(0x0102)));
+///	assert!(matches!(reader.read_bytes(2), Ok([b'o', b'k'])));
+///	assert!(reader.is_empty());
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstByteReader<'a> {
+	data: &'a [u8],
+	pos: usize,
+}
+
+/// Generates fixed-width `read_*` methods on top of `read_array`.
+macro_rules! impl_read_bin {
+	[
+		$(
+			$(#[$meta:meta])*
+			$name:ident: $ty:ty => $from_bytes:ident;
+		)*
+	] => {
+		$(
+			$(#[$meta])*
+			#[inline]
+			pub const fn $name(&mut self) -> Result<$ty, ReadError> {
+				match self.read_array::<{ size_of::<$ty>() }>() {
+					Ok(a) => Ok(<$ty>::$from_bytes(a)),
+					Err(e) => Err(e),
+				}
+			}
+		)*
+	};
+}
+
+impl<'a> ConstByteReader<'a> {
+	/// Creates a cursor at the beginning of `data`.
+	#[inline]
+	pub const fn new(data: &'a [u8]) -> Self {
+		Self { data, pos: 0 }
+	}
+
+	/// Current position of the cursor.
+	#[inline]
+	pub const fn pos(&self) -> usize {
+		self.pos
+	}
+
+	/// Number of bytes not yet read.
+	#[inline]
+	pub const fn remaining(&self) -> usize {
+		self.data.len() - self.pos
+	}
+
+	/// Determine if all data has been read.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.remaining() == 0
+	}
+
+	/// Returns the bytes not yet read, without advancing.
+	#[inline]
+	pub const fn remaining_bytes(&self) -> &'a [u8] {
+		let (_, tail) = self.data.split_at(self.pos);
+		tail
+	}
+
+	/// Returns the next byte without advancing.
+	#[inline]
+	pub const fn peek_u8(&self) -> Result<u8, ReadError> {
+		match self.remaining_bytes().first() {
+			Some(a) => Ok(*a),
+			None => Err(ReadError::UnexpectedEof),
+		}
+	}
+
+	/// Skips `n` bytes.
+	pub const fn skip(&mut self, n: usize) -> Result<(), ReadError> {
+		match self.read_bytes(n) {
+			Ok(_) => Ok(()),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Reads `n` raw bytes.
+	pub const fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], ReadError> {
+		if n > self.remaining() {
+			return Err(ReadError::UnexpectedEof);
+		}
+
+		let (result, _) = self.remaining_bytes().split_at(n);
+		self.pos += n;
+
+		Ok(result)
+	}
+
+	/// Reads `N` raw bytes into an array.
+	pub const fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ReadError> {
+		let bytes = match self.read_bytes(N) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+
+		let mut result = [0u8; N];
+		let mut i = 0;
+		while i < N {
+			result[i] = bytes[i];
+			i += 1;
+		}
+
+		Ok(result)
+	}
+
+	/// Reads `n` bytes, validating them as UTF-8.
+	pub const fn read_str(&mut self, n: usize) -> Result<&'a str, ReadError> {
+		if n > self.remaining() {
+			return Err(ReadError::UnexpectedEof);
+		}
+
+		let (bytes, _) = self.remaining_bytes().split_at(n);
+		match core::str::from_utf8(bytes) {
+			Ok(a) => {
+				self.pos += n;
+				Ok(a)
+			}
+			Err(e) => Err(ReadError::Utf8(e)),
+		}
+	}
+
+	impl_read_bin! {
+		/// Reads `u8`.
+		read_u8: u8 => from_le_bytes;
+		/// Reads `i8`.
+		read_i8: i8 => from_le_bytes;
+
+		/// Reads `u16` in little-endian byte order.
+		read_u16_le: u16 => from_le_bytes;
+		/// Reads `u16` in big-endian byte order.
+		read_u16_be: u16 => from_be_bytes;
+		/// Reads `u32` in little-endian byte order.
+		read_u32_le: u32 => from_le_bytes;
+		/// Reads `u32` in big-endian byte order.
+		read_u32_be: u32 => from_be_bytes;
+		/// Reads `u64` in little-endian byte order.
+		read_u64_le: u64 => from_le_bytes;
+		/// Reads `u64` in big-endian byte order.
+		read_u64_be: u64 => from_be_bytes;
+		/// Reads `u128` in little-endian byte order.
+		read_u128_le: u128 => from_le_bytes;
+		/// Reads `u128` in big-endian byte order.
+		read_u128_be: u128 => from_be_bytes;
+
+		/// Reads `i16` in little-endian byte order.
+		read_i16_le: i16 => from_le_bytes;
+		/// Reads `i16` in big-endian byte order.
+		read_i16_be: i16 => from_be_bytes;
+		/// Reads `i32` in little-endian byte order.
+		read_i32_le: i32 => from_le_bytes;
+		/// Reads `i32` in big-endian byte order.
+		read_i32_be: i32 => from_be_bytes;
+		/// Reads `i64` in little-endian byte order.
+		read_i64_le: i64 => from_le_bytes;
+		/// Reads `i64` in big-endian byte order.
+		read_i64_be: i64 => from_be_bytes;
+		/// Reads `i128` in little-endian byte order.
+		read_i128_le: i128 => from_le_bytes;
+		/// Reads `i128` in big-endian byte order.
+		read_i128_be: i128 => from_be_bytes;
+
+		/// Reads `f32` (IEEE 754) in little-endian byte order.
+		read_f32_le: f32 => from_le_bytes;
+		/// Reads `f32` (IEEE 754) in big-endian byte order.
+		read_f32_be: f32 => from_be_bytes;
+		/// Reads `f64` (IEEE 754) in little-endian byte order.
+		read_f64_le: f64 => from_le_bytes;
+		/// Reads `f64` (IEEE 754) in big-endian byte order.
+		read_f64_be: f64 => from_be_bytes;
+	}
+
+	/// Reads an unsigned LEB128 value into `u64`.
+	pub const fn read_uleb128(&mut self) -> Result<u64, ReadError> {
+		let data = self.data;
+		let mut pos = self.pos;
+		let mut result = 0u64;
+		let mut shift = 0;
+		loop {
+			if pos >= data.len() {
+				return Err(ReadError::UnexpectedEof);
+			}
+			let byte = data[pos];
+			pos += 1;
+
+			let low = (byte & 0x7F) as u64;
+			if shift == 63 && (low > 1 || (byte & 0x80) != 0) {
+				return Err(ReadError::Leb128Overflow);
+			}
+			result |= low << shift;
+
+			if (byte & 0x80) == 0 {
+				break;
+			}
+			shift += 7;
+		}
+
+		self.pos = pos;
+		Ok(result)
+	}
+
+	/// Reads a signed LEB128 value into `i64`.
+	pub const fn read_sleb128(&mut self) -> Result<i64, ReadError> {
+		let data = self.data;
+		let mut pos = self.pos;
+		let mut result = 0i64;
+		let mut shift = 0;
+		loop {
+			if pos >= data.len() {
+				return Err(ReadError::UnexpectedEof);
+			}
+			let byte = data[pos];
+			pos += 1;
+
+			let low = byte & 0x7F;
+			if shift == 63 && ((low != 0 && low != 0x7F) || (byte & 0x80) != 0) {
+				return Err(ReadError::Leb128Overflow);
+			}
+			result |= (low as i64) << shift;
+			shift += 7;
+
+			if (byte & 0x80) == 0 {
+				if shift < 64 && (byte & 0x40) != 0 {
+					result |= -1i64 << shift; // sign extension
+				}
+				break;
+			}
+		}
+
+		self.pos = pos;
+		Ok(result)
+	}
+
+	/// Reads a zigzag-encoded unsigned LEB128 value (protobuf `sint64`).
+	pub const fn read_zigzag(&mut self) -> Result<i64, ReadError> {
+		match self.read_uleb128() {
+			Ok(a) => Ok(((a >> 1) as i64) ^ -((a & 1) as i64)),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Returns a cursor for reading back the written bytes.
+	#[inline]
+	pub const fn reader(&self) -> ConstByteReader<'_> {
+		ConstByteReader::new(self.as_bytes())
+	}
+}
+
+/// Error type returned by `ConstByteReader`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError {
+	/// Not enough bytes left.
+	UnexpectedEof,
+	/// The bytes are not valid UTF-8.
+	Utf8(core::str::Utf8Error),
+	/// The LEB128 value does not fit into the target type.
+	Leb128Overflow,
+}
+
+impl Display for ReadError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::UnexpectedEof => f.write_str("unexpected end of data"),
+			Self::Utf8(e) => Display::fmt(e, f),
+			Self::Leb128Overflow => f.write_str("LEB128 value is too large"),
+		}
+	}
+}
+
+impl core::error::Error for ReadError {}
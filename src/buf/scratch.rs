@@ -0,0 +1,76 @@
+//! Fixed-size scratch space for formatting values before they are appended.
+
+use crate::buf::HexCase;
+
+/// Small stack array with a write position.
+///
+/// Formatters write into `Scratch` first, so a value is appended to a `ConstByteBuf`
+/// in a single write and nothing is written on overflow.
+pub(crate) struct Scratch<const N: usize> {
+	buf: [u8; N],
+	len: usize,
+}
+
+impl<const N: usize> Scratch<N> {
+	pub(crate) const fn new() -> Self {
+		Self {
+			buf: [0; N],
+			len: 0,
+		}
+	}
+
+	pub(crate) const fn push(&mut self, bytes: &[u8]) {
+		let mut i = 0;
+		while i < bytes.len() {
+			self.push_byte(bytes[i]);
+			i += 1;
+		}
+	}
+
+	pub(crate) const fn push_byte(&mut self, byte: u8) {
+		self.buf[self.len] = byte;
+		self.len += 1;
+	}
+
+	/// Two decimal digits, zero-padded.
+	pub(crate) const fn push_2digits(&mut self, value: u8) {
+		self.push(&[b'0' + value / 10, b'0' + value % 10]);
+	}
+
+	/// Decimal digits, zero-padded to `min_digits`.
+	pub(crate) const fn push_decimal(&mut self, mut value: u64, min_digits: usize) {
+		let mut digits = [b'0'; 20];
+		let mut i = digits.len();
+		while value != 0 {
+			i -= 1;
+			digits[i] = b'0' + (value % 10) as u8;
+			value /= 10;
+		}
+		if i > digits.len() - min_digits {
+			i = digits.len() - min_digits;
+		}
+
+		let (_, digits) = digits.split_at(i);
+		self.push(digits);
+	}
+
+	/// Lowercase hexadecimal digits without leading zeros (`0` for zero).
+	pub(crate) const fn push_hex(&mut self, value: u64) {
+		let mut shift = 60;
+		while shift > 0 && (value >> shift) == 0 {
+			shift -= 4;
+		}
+		loop {
+			self.push_byte(HexCase::Lower.digits()[((value >> shift) & 0xF) as usize]);
+			if shift == 0 {
+				break;
+			}
+			shift -= 4;
+		}
+	}
+
+	pub(crate) const fn as_bytes(&self) -> &[u8] {
+		let (data, _) = self.buf.split_at(self.len);
+		data
+	}
+}
@@ -0,0 +1,46 @@
+//! Const formatting of semantic versions.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use crate::buf::StackOverflow;
+use crate::semver::Semver;
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Appends a semantic version (`MAJOR.MINOR.PATCH[-PRE][+BUILD]`).
+	///
+	/// Panics on overflow.
+	#[track_caller]
+	pub const fn push_semver(&mut self, version: &Semver<'_>) -> usize {
+		match self.try_push_semver(version) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a semantic version (`MAJOR.MINOR.PATCH[-PRE][+BUILD]`).
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_semver(&mut self, version: &Semver<'_>) -> Result<usize, StackOverflow> {
+		let len = version.formatted_len();
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let _ = self._try_push_u64(version.major);
+		self.push_char('.');
+		let _ = self._try_push_u64(version.minor);
+		self.push_char('.');
+		let _ = self._try_push_u64(version.patch);
+		if !version.pre.is_empty() {
+			self.push_char('-');
+			self.push_str(version.pre);
+		}
+		if !version.build.is_empty() {
+			self.push_char('+');
+			self.push_str(version.build);
+		}
+
+		Ok(len)
+	}
+}
@@ -1,4 +1,4 @@
-//! Trait for estimating the maximum decimal length of a type's value.
+//! Traits for estimating the maximum encoded length of a type's value.
 
 /// Trait for estimating the maximum decimal length of a type's value.
 ///
@@ -28,6 +28,125 @@ impl ConstByteBufSize for char {
 	};
 }
 
+/// Trait for estimating the maximum LEB128 length of an integer type's value.
+///
+/// Covers unsigned LEB128 for unsigned types and signed/zigzag LEB128 for signed types.
+pub trait ConstByteBufLeb128Size {
+	/// The maximum number of bytes needed to encode this type as LEB128.
+	const MAX_LEB128_LEN: usize;
+}
+
+impl_leb128_buf_size! {
+	usize, u128, u64, u32, u16, u8,
+	isize, i128, i64, i32, i16, i8
+}
+
+/// Trait for estimating the maximum formatted length of a value that is not a number.
+pub trait ConstByteBufDisplaySize {
+	/// The maximum number of bytes needed to format any value of this type.
+	const MAX_DISPLAY_LEN: usize;
+}
+
+impl ConstByteBufDisplaySize for core::net::Ipv4Addr {
+	/// `255.255.255.255`
+	const MAX_DISPLAY_LEN: usize = 4 * 3 + 3;
+}
+
+impl ConstByteBufDisplaySize for core::net::Ipv6Addr {
+	/// `ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff`
+	const MAX_DISPLAY_LEN: usize = 8 * 4 + 7;
+}
+
+impl ConstByteBufDisplaySize for core::net::IpAddr {
+	/// The longer of IPv4 and IPv6.
+	const MAX_DISPLAY_LEN: usize = core::net::Ipv6Addr::MAX_DISPLAY_LEN;
+}
+
+impl ConstByteBufDisplaySize for core::net::SocketAddrV4 {
+	/// `255.255.255.255:65535`
+	const MAX_DISPLAY_LEN: usize = core::net::Ipv4Addr::MAX_DISPLAY_LEN + 1 + u16::MAX_DECIMAL_LEN;
+}
+
+impl ConstByteBufDisplaySize for core::net::SocketAddrV6 {
+	/// `[ipv6%scope_id]:65535`
+	const MAX_DISPLAY_LEN: usize = 1
+		+ core::net::Ipv6Addr::MAX_DISPLAY_LEN
+		+ 1 + u32::MAX_DECIMAL_LEN
+		+ 2 + u16::MAX_DECIMAL_LEN;
+}
+
+impl ConstByteBufDisplaySize for core::net::SocketAddr {
+	/// The longer of `SocketAddrV4` and `SocketAddrV6`.
+	const MAX_DISPLAY_LEN: usize = core::net::SocketAddrV6::MAX_DISPLAY_LEN;
+}
+
+/// Number of decimal digits of `value`.
+#[inline]
+pub const fn decimal_len(value: u64) -> usize {
+	crate::decimal_len(value)
+}
+
+/// Number of bytes of `data_len` bytes encoded as hexadecimal digit pairs
+/// with `separator` between the pairs.
+pub const fn hex_encoded_len(data_len: usize, separator: &str) -> usize {
+	match data_len {
+		0 => 0,
+		_ => data_len * 2 + (data_len - 1) * separator.len(),
+	}
+}
+
+/// Maximum number of bytes of `len` bytes of text escaped with `escape`.
+pub const fn escaped_max_len(len: usize, escape: crate::buf::Escape) -> usize {
+	len * escape.max_expansion()
+}
+
+/// Maximum number of bytes of `len` bytes formatted as a byte string literal:
+/// `b""` and `\xff` per byte.
+pub const fn debug_bytes_max_len(len: usize) -> usize {
+	3 + len * 4
+}
+
+/// Upper bound of the length of `segments` joined and normalized as a path.
+///
+/// Normalization never grows a segment; at most one separator is added per segment.
+pub const fn path_max_len(segments: &[&str]) -> usize {
+	let mut len = 0;
+	let mut i = 0;
+	while i < segments.len() {
+		len += segments[i].len() + 1;
+		i += 1;
+	}
+
+	len
+}
+
+/// Maximum number of bytes of `fields` written as one CSV row: every field quoted
+/// with all bytes doubled, the separators and `\r\n`.
+pub const fn csv_row_max_len(fields: &[&str]) -> usize {
+	let mut len = 2;
+	let mut i = 0;
+	while i < fields.len() {
+		len += fields[i].len() * 2 + 2 + 1;
+		i += 1;
+	}
+
+	len
+}
+
+/// Implements `ConstByteBufLeb128Size` for integer types: 7 payload bits per byte.
+macro_rules! impl_leb128_buf_size {
+	[ $($ty:ty),* $(,)? ] => {
+		$(
+			impl ConstByteBufLeb128Size for $ty {
+				/// Max LEB128 bytes needed to encode any `
+				#[doc = stringify!($ty)]
+				#[doc = "`"]
+				const MAX_LEB128_LEN: usize = (<$ty>::BITS as usize).div_ceil(7);
+			}
+		)*
+	};
+}
+
 /// Implements `ConstByteBufSize` for numeric types using either their `.MAX` or `.MIN` value.
 ///
 /// ## Patterns:
@@ -96,4 +215,5 @@ macro_rules! impl_numconst_buf_size {
 	() => {}
 }
 
+pub(crate) use impl_leb128_buf_size;
 pub(crate) use impl_numconst_buf_size;
@@ -0,0 +1,209 @@
+//! Const formatting of Unix timestamps.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::StackOverflow;
+use crate::buf::Utf8SafeBuf;
+use crate::buf::scratch::Scratch;
+
+/// Output format of [`ConstStrBuf::push_unix_timestamp`](crate::buf::ConstStrBuf::push_unix_timestamp).
+///
+/// Years outside `0..=9999` are written with a sign and at least four digits
+/// (ISO 8601 expanded representation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampStyle {
+	/// ISO 8601 date: `2023-11-14`.
+	Date,
+	/// ISO 8601 / RFC 3339 date and time in UTC: `2023-11-14T22:13:20Z`.
+	DateTime,
+	/// RFC 2822 date and time: `Tue, 14 Nov 2023 22:13:20 +0000`.
+	///
+	/// RFC 2822 has no signed years, so the output for years outside `0..=9999`
+	/// (e.g. `+10000`) does not conform to it.
+	Rfc2822,
+}
+
+/// Sign and the longest year of an `i64` timestamp (`292277026596`).
+const MAX_YEAR_LEN: usize = 1 + 12;
+
+impl TimestampStyle {
+	/// Maximum number of bytes produced for any `i64` timestamp.
+	pub const fn max_len(self) -> usize {
+		match self {
+			Self::Date => MAX_YEAR_LEN + "-MM-DD".len(),
+			Self::DateTime => MAX_YEAR_LEN + "-MM-DDTHH:MM:SSZ".len(),
+			Self::Rfc2822 => "Www, DD Mmm ".len() + MAX_YEAR_LEN + " HH:MM:SS +0000".len(),
+		}
+	}
+}
+
+/// Broken-down UTC date and time.
+struct Civil {
+	year: i64,
+	month: u8,
+	day: u8,
+	hour: u8,
+	minute: u8,
+	second: u8,
+	/// `0` is Sunday.
+	weekday: u8,
+}
+
+impl Civil {
+	/// Converts seconds since the Unix epoch into a proleptic Gregorian date.
+	///
+	/// Based on Howard Hinnant's `civil_from_days`.
+	const fn from_unix(secs: i64) -> Self {
+		let days = secs.div_euclid(86400);
+		let secs_of_day = secs.rem_euclid(86400);
+
+		let z = days + 719468; // shift the epoch to 0000-03-01
+		let era = z.div_euclid(146097);
+		let doe = z.rem_euclid(146097); // [0, 146096]
+		let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+		let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+		let mp = (5 * doy + 2) / 153; // [0, 11], March-based
+		let day = doy - (153 * mp + 2) / 5 + 1;
+		let month = if mp < 10 { mp + 3 } else { mp - 9 };
+		let year = yoe + era * 400 + (month <= 2) as i64;
+
+		Self {
+			year,
+			month: month as u8,
+			day: day as u8,
+			hour: (secs_of_day / 3600) as u8,
+			minute: (secs_of_day / 60 % 60) as u8,
+			second: (secs_of_day % 60) as u8,
+			weekday: (days + 4).rem_euclid(7) as u8, // 1970-01-01 was a Thursday
+		}
+	}
+}
+
+/// Scratch space for the longest formatted timestamp.
+type TimestampScratch = Scratch<{ TimestampStyle::Rfc2822.max_len() }>;
+
+/// Appends the year with at least four digits, signed outside `0..=9999`.
+const fn push_year(out: &mut TimestampScratch, year: i64) {
+	if year < 0 {
+		out.push_byte(b'-');
+	} else if year > 9999 {
+		out.push_byte(b'+');
+	}
+
+	out.push_decimal(year.unsigned_abs(), 4);
+}
+
+/// Appends `HH:MM:SS`.
+const fn push_time(out: &mut TimestampScratch, civil: &Civil) {
+	out.push_2digits(civil.hour);
+	out.push_byte(b':');
+	out.push_2digits(civil.minute);
+	out.push_byte(b':');
+	out.push_2digits(civil.second);
+}
+
+const WEEKDAYS: [&[u8; 3]; 7] = [b"Sun", b"Mon", b"Tue", b"Wed", b"Thu", b"Fri", b"Sat"];
+const MONTHS: [&[u8; 3]; 12] = [
+	b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+];
+
+impl<const CAP: usize> ConstByteBuf<CAP, Utf8SafeBuf> {
+	/// Appends a Unix timestamp (seconds since 1970-01-01 UTC) formatted with `style`.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::{ConstStrBuf, TimestampStyle};
+	///
+	/// const DATE: ConstStrBuf<{ TimestampStyle::DateTime.max_len() }> = {
+	///	let mut buf = ConstStrBuf::new();
+	///	buf.push_unix_timestamp(1_700_000_000, TimestampStyle::DateTime);
+	///	buf
+	/// };
+	/// assert_eq!(DATE, "2023-11-14T22:13:20Z");
+	/// ```
+	#[track_caller]
+	pub const fn push_unix_timestamp(&mut self, secs: i64, style: TimestampStyle) -> usize {
+		match self.try_push_unix_timestamp(secs, style) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a Unix timestamp (seconds since 1970-01-01 UTC) formatted with `style`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_unix_timestamp(
+		&mut self,
+		secs: i64,
+		style: TimestampStyle,
+	) -> Result<usize, StackOverflow> {
+		let civil = Civil::from_unix(secs);
+		let mut out = TimestampScratch::new();
+		match style {
+			TimestampStyle::Date | TimestampStyle::DateTime => {
+				push_year(&mut out, civil.year);
+				out.push(b"-");
+				out.push_2digits(civil.month);
+				out.push(b"-");
+				out.push_2digits(civil.day);
+				if let TimestampStyle::DateTime = style {
+					out.push(b"T");
+					push_time(&mut out, &civil);
+					out.push(b"Z");
+				}
+			}
+			TimestampStyle::Rfc2822 => {
+				out.push(WEEKDAYS[civil.weekday as usize]);
+				out.push(b", ");
+				out.push_2digits(civil.day);
+				out.push(b" ");
+				out.push(MONTHS[civil.month as usize - 1]);
+				out.push(b" ");
+				push_year(&mut out, civil.year);
+				out.push(b" ");
+				push_time(&mut out, &civil);
+				out.push(b" +0000");
+			}
+		}
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+}
+
+/// Compile-time formatted Unix timestamp as `&'static str`.
+///
+/// The seconds must be an `i64`. The style defaults to
+/// [`DateTime`](crate::buf::TimestampStyle::DateTime); pass `Date`, `DateTime` or
+/// `Rfc2822` as the second argument to select another one.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::timestamp_str;
+///
+/// const BUILT: &str = timestamp_str!(1_700_000_000);
+/// const BUILT_DATE: &str = timestamp_str!(1_700_000_000, Date);
+/// const BUILT_MAIL: &str = timestamp_str!(1_700_000_000, Rfc2822);
+///
+/// assert_eq!(BUILT, "2023-11-14T22:13:20Z");
+/// assert_eq!(BUILT_DATE, "2023-11-14");
+/// assert_eq!(BUILT_MAIL, "Tue, 14 Nov 2023 22:13:20 +0000");
+/// ```
+///
+/// ```rust,compile_fail
+/// const SECS: u64 = u64::MAX;
+/// const BUILT: &str = cluConstData::timestamp_str!(SECS); // expected `i64`, found `u64`
+/// ```
+#[macro_export]
+macro_rules! timestamp_str {
+	[$secs: expr $(,)?] => {
+		$crate::timestamp_str!($secs, DateTime)
+	};
+	[$secs: expr, $style: ident $(,)?] => {
+		$crate::__buf_str!($crate::buf::TimestampStyle::$style.max_len(), {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_unix_timestamp($secs, $crate::buf::TimestampStyle::$style);
+			buf
+		})
+	};
+}
@@ -0,0 +1,335 @@
+//! Const percent-encoding (RFC 3986) and URL building.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use crate::buf::HexCase;
+use crate::buf::StackOverflow;
+use crate::buf::size::decimal_len;
+use core::fmt::Display;
+
+/// Set of characters that are written as-is by
+/// [`ConstByteBuf::push_url_component`]; every other byte is percent-encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UrlEncodeSet {
+	/// Unreserved characters only: `A-Z a-z 0-9 - . _ ~`.
+	Unreserved,
+	/// A path segment (`pchar`): unreserved, sub-delims, `:` and `@`.
+	PathSegment,
+	/// A query key or value: like `PathSegment` plus `/` and `?`, but without
+	/// `&`, `=` and `+`.
+	QueryValue,
+}
+
+impl UrlEncodeSet {
+	/// `true` if `c` is written without encoding.
+	pub const fn is_allowed(self, c: u8) -> bool {
+		let unreserved = c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~');
+		match self {
+			Self::Unreserved => unreserved,
+			Self::PathSegment => unreserved || is_sub_delim(c) || matches!(c, b':' | b'@'),
+			Self::QueryValue => {
+				(unreserved || is_sub_delim(c) || matches!(c, b':' | b'@' | b'/' | b'?'))
+					&& !matches!(c, b'&' | b'=' | b'+')
+			}
+		}
+	}
+
+	/// Number of bytes of `s` after percent-encoding.
+	pub const fn encoded_len(self, s: &str) -> usize {
+		let s = s.as_bytes();
+		let mut len = 0;
+		let mut i = 0;
+		while i < s.len() {
+			len += if self.is_allowed(s[i]) { 1 } else { 3 };
+			i += 1;
+		}
+
+		len
+	}
+}
+
+/// `!$&'()*+,;=`
+const fn is_sub_delim(c: u8) -> bool {
+	matches!(
+		c,
+		b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+	)
+}
+
+/// Parts of a URL: `scheme://host[:port][/path/segments][?key=value&...]`.
+///
+/// Path segments and query pairs are percent-encoded when written;
+/// the scheme and host are validated but never encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UrlParts<'a> {
+	/// Scheme, e.g. `https`.
+	pub scheme: &'a str,
+	/// Registered name, IPv4 address or bracketed IPv6 address.
+	pub host: &'a str,
+	/// Optional port.
+	pub port: Option<u16>,
+	/// Path segments, each written after a `/`.
+	pub path: &'a [&'a str],
+	/// Query pairs.
+	pub query: &'a [(&'a str, &'a str)],
+}
+
+impl UrlParts<'_> {
+	/// Validates the scheme and host.
+	pub const fn validate(&self) -> Result<(), UrlError> {
+		// ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+		let scheme = self.scheme.as_bytes();
+		if scheme.is_empty() || !scheme[0].is_ascii_alphabetic() {
+			return Err(UrlError::InvalidScheme);
+		}
+		let mut i = 1;
+		while i < scheme.len() {
+			let c = scheme[i];
+			if !(c.is_ascii_alphanumeric() || matches!(c, b'+' | b'-' | b'.')) {
+				return Err(UrlError::InvalidScheme);
+			}
+			i += 1;
+		}
+
+		let host = self.host.as_bytes();
+		match host {
+			[] => return Err(UrlError::InvalidHost),
+			[b'[', ip @ .., b']'] => match core::str::from_utf8(ip) {
+				Ok(ip) if crate::net::parse_ipv6(ip).is_ok() => {}
+				_ => return Err(UrlError::InvalidHost),
+			},
+			_ => {
+				let mut i = 0;
+				while i < host.len() {
+					let c = host[i];
+					if !(UrlEncodeSet::Unreserved.is_allowed(c) || is_sub_delim(c)) {
+						return Err(UrlError::InvalidHost);
+					}
+					i += 1;
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Validates the parts and returns the exact length of the formatted URL.
+	pub const fn formatted_len(&self) -> Result<usize, UrlError> {
+		if let Err(e) = self.validate() {
+			return Err(e);
+		}
+
+		let mut len = self.scheme.len() + "://".len() + self.host.len();
+		if let Some(port) = self.port {
+			len += 1 + decimal_len(port as u64);
+		}
+		let mut i = 0;
+		while i < self.path.len() {
+			len += 1 + UrlEncodeSet::PathSegment.encoded_len(self.path[i]);
+			i += 1;
+		}
+		let mut i = 0;
+		while i < self.query.len() {
+			let (key, value) = self.query[i];
+			len += 1 + UrlEncodeSet::QueryValue.encoded_len(key);
+			len += 1 + UrlEncodeSet::QueryValue.encoded_len(value);
+			i += 1;
+		}
+
+		Ok(len)
+	}
+}
+
+/// Error type returned by [`UrlParts::validate`] and [`ConstByteBuf::try_push_url`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UrlError {
+	/// The scheme is empty or contains a character outside `A-Z a-z 0-9 + - .`.
+	InvalidScheme,
+	/// The host is empty, contains a character that would need encoding,
+	/// or is a bracketed literal that is not an IPv6 address.
+	InvalidHost,
+	/// The buffer has not enough space left.
+	Overflow,
+}
+
+impl UrlError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::InvalidScheme => "invalid URL: invalid scheme",
+			Self::InvalidHost => "invalid URL: invalid host",
+			Self::Overflow => "ConstByteBuf overflow: capacity exceeded",
+		}
+	}
+}
+
+impl Display for UrlError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for UrlError {}
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Appends `s`, percent-encoding every byte not allowed by `set` (`%2F`).
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::{ConstStrBuf, UrlEncodeSet};
+	///
+	/// let mut buf = ConstStrBuf::<32>::new();
+	/// buf.push_url_component("a b/c&d", UrlEncodeSet::QueryValue);
+	/// assert_eq!(buf, "a%20b/c%26d");
+	/// ```
+	#[track_caller]
+	pub const fn push_url_component(&mut self, s: &str, set: UrlEncodeSet) -> usize {
+		match self.try_push_url_component(s, set) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends `s`, percent-encoding every byte not allowed by `set` (`%2F`).
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_url_component(
+		&mut self,
+		s: &str,
+		set: UrlEncodeSet,
+	) -> Result<usize, StackOverflow> {
+		let len = set.encoded_len(s);
+		if len > self.available() {
+			return Err(StackOverflow);
+		}
+
+		// cannot fail: the length was checked above
+		let s = s.as_bytes();
+		let mut i = 0;
+		while i < s.len() {
+			let c = s[i];
+			let _ = match set.is_allowed(c) {
+				true => self.__try_write_byte(c),
+				false => {
+					let [hi, lo] = HexCase::Upper.encode(c);
+					self.__try_write_bytes_unchecked(&[b'%', hi, lo])
+				}
+			};
+			i += 1;
+		}
+
+		Ok(len)
+	}
+
+	/// Appends a URL assembled from `parts`.
+	///
+	/// Panics on invalid parts or overflow.
+	#[track_caller]
+	pub const fn push_url(&mut self, parts: &UrlParts<'_>) -> usize {
+		match self.try_push_url(parts) {
+			Ok(a) => a,
+			Err(UrlError::Overflow) => Self::cold_overflow_panic(),
+			Err(e) => panic!("{}", e.as_str()),
+		}
+	}
+
+	/// Appends a URL assembled from `parts`.
+	///
+	/// Nothing is written on invalid parts or overflow.
+	pub const fn try_push_url(&mut self, parts: &UrlParts<'_>) -> Result<usize, UrlError> {
+		let len = match parts.formatted_len() {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		if len > self.available() {
+			return Err(UrlError::Overflow);
+		}
+
+		// cannot fail: the length was checked above
+		let _ = self.__try_write_bytes_unchecked(parts.scheme.as_bytes());
+		let _ = self.__try_write_bytes_unchecked(b"://");
+		let _ = self.__try_write_bytes_unchecked(parts.host.as_bytes());
+		if let Some(port) = parts.port {
+			let _ = self.__try_write_byte(b':');
+			let _ = self._try_push_u64(port as u64);
+		}
+		let mut i = 0;
+		while i < parts.path.len() {
+			let _ = self.__try_write_byte(b'/');
+			let _ = self.try_push_url_component(parts.path[i], UrlEncodeSet::PathSegment);
+			i += 1;
+		}
+		let mut i = 0;
+		while i < parts.query.len() {
+			let (key, value) = parts.query[i];
+			let _ = self.__try_write_byte(if i == 0 { b'?' } else { b'&' });
+			let _ = self.try_push_url_component(key, UrlEncodeSet::QueryValue);
+			let _ = self.__try_write_byte(b'=');
+			let _ = self.try_push_url_component(value, UrlEncodeSet::QueryValue);
+			i += 1;
+		}
+
+		Ok(len)
+	}
+}
+
+/// Compile-time URL as `&'static str`.
+///
+/// Takes `scheme` and `host`, then optionally `port`, `path` segments and `query` pairs
+/// (in this order). The scheme and host are validated; path segments, query keys and
+/// values are percent-encoded. Invalid parts are a compile error.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::const_url;
+///
+/// const API_VERSION: &str = "v1";
+/// const ENDPOINT: &str = const_url! {
+///	scheme: "https",
+///	host: "api.example.com",
+///	port: 8443,
+///	path: [API_VERSION, "user list"],
+///	query: [("q", "a&b"), ("lang", "en")],
+/// };
+///
+/// assert_eq!(ENDPOINT, "https://api.example.com:8443/v1/user%20list?q=a%26b&lang=en");
+/// ```
+///
+/// ```rust,compile_fail
+/// const ENDPOINT: &str = cluConstData::const_url!(scheme: "https", host: "bad host");
+/// ```
+#[macro_export]
+macro_rules! const_url {
+	[
+		scheme: $scheme: expr,
+		host: $host: expr
+		$(, port: $port: expr)?
+		$(, path: [$($segment: expr),* $(,)?])?
+		$(, query: [$(($key: expr, $value: expr)),* $(,)?])?
+		$(,)?
+	] => {{
+		const _PARTS: $crate::buf::UrlParts<'static> = $crate::buf::UrlParts {
+			scheme: $scheme,
+			host: $host,
+			port: $crate::const_url!(@port $($port)?),
+			path: &[$($($segment),*)?],
+			query: &[$($(($key, $value)),*)?],
+		};
+		const _LEN: usize = match _PARTS.formatted_len() {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		$crate::__buf_str!(_LEN, {
+			let mut buf = $crate::buf::ConstStrBuf::new();
+			buf.push_url(&_PARTS);
+			buf
+		})
+	}};
+
+	[@port] => { None };
+	[@port $port: expr] => { Some($port) };
+}
@@ -0,0 +1,48 @@
+//! Internal const UTF-8 helpers.
+
+/// Decodes the character starting at `pos`.
+///
+/// Returns the character and its encoded length in bytes.
+/// `bytes` must be valid UTF-8 and `pos` must be a char boundary inside it.
+pub(crate) const fn decode_char_at(bytes: &[u8], pos: usize) -> (char, usize) {
+	let first = bytes[pos];
+	let (len, mut code) = match first {
+		0x00..=0x7F => return (first as char, 1),
+		0xC0..=0xDF => (2, (first & 0x1F) as u32),
+		0xE0..=0xEF => (3, (first & 0x0F) as u32),
+		_ => (4, (first & 0x07) as u32),
+	};
+
+	let mut i = 1;
+	while i < len {
+		code = (code << 6) | (bytes[pos + i] & 0x3F) as u32;
+		i += 1;
+	}
+
+	match char::from_u32(code) {
+		Some(a) => (a, len),
+		None => (char::REPLACEMENT_CHARACTER, len),
+	}
+}
+
+/// Returns the start of the last character in `bytes`, or `None` if it is empty.
+///
+/// `bytes` must be valid UTF-8.
+pub(crate) const fn last_char_start(bytes: &[u8]) -> Option<usize> {
+	if bytes.is_empty() {
+		return None;
+	}
+
+	let mut pos = bytes.len() - 1;
+	while pos > 0 && is_continuation_byte(bytes[pos]) {
+		pos -= 1;
+	}
+
+	Some(pos)
+}
+
+/// Returns `true` for UTF-8 continuation bytes (`0b10xx_xxxx`).
+#[inline]
+pub(crate) const fn is_continuation_byte(b: u8) -> bool {
+	(b & 0xC0) == 0x80
+}
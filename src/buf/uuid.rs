@@ -0,0 +1,95 @@
+//! Const formatting of UUIDs.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteBufData;
+use crate::buf::HexCase;
+use crate::buf::StackOverflow;
+use crate::buf::scratch::Scratch;
+
+/// Output format of [`ConstByteBuf::push_uuid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UuidStyle {
+	/// `550e8400-e29b-41d4-a716-446655440000`
+	Hyphenated,
+	/// `550e8400e29b41d4a716446655440000`
+	Simple,
+	/// `{550e8400-e29b-41d4-a716-446655440000}`
+	Braced,
+	/// `urn:uuid:550e8400-e29b-41d4-a716-446655440000`
+	Urn,
+}
+
+impl UuidStyle {
+	/// Number of bytes of a UUID formatted in this style.
+	pub const fn formatted_len(self) -> usize {
+		match self {
+			Self::Hyphenated => 36,
+			Self::Simple => 32,
+			Self::Braced => 38,
+			Self::Urn => 45,
+		}
+	}
+}
+
+impl<const CAP: usize, TData: ConstByteBufData> ConstByteBuf<CAP, TData> {
+	/// Appends a UUID formatted with `style` and `case`.
+	///
+	/// Panics on overflow.
+	///
+	/// # Example
+	/// ```rust
+	/// use cluConstData::buf::{ConstStrBuf, HexCase, UuidStyle};
+	/// use cluConstData::uuid_bytes;
+	///
+	/// const ID: ConstStrBuf<{ UuidStyle::Braced.formatted_len() }> = {
+	///	let mut buf = ConstStrBuf::new();
+	///	buf.push_uuid(
+	///		&uuid_bytes!("550e8400e29b41d4a716446655440000"),
+	///		UuidStyle::Braced,
+	///		HexCase::Upper,
+	///	);
+	///	buf
+	/// };
+	/// assert_eq!(ID, "{550E8400-E29B-41D4-A716-446655440000}");
+	/// ```
+	#[track_caller]
+	pub const fn push_uuid(&mut self, uuid: &[u8; 16], style: UuidStyle, case: HexCase) -> usize {
+		match self.try_push_uuid(uuid, style, case) {
+			Ok(a) => a,
+			Err(_) => Self::cold_overflow_panic(),
+		}
+	}
+
+	/// Appends a UUID formatted with `style` and `case`.
+	///
+	/// Nothing is written on overflow.
+	pub const fn try_push_uuid(
+		&mut self,
+		uuid: &[u8; 16],
+		style: UuidStyle,
+		case: HexCase,
+	) -> Result<usize, StackOverflow> {
+		let mut out = Scratch::<{ UuidStyle::Urn.formatted_len() }>::new();
+		match style {
+			UuidStyle::Braced => out.push_byte(b'{'),
+			UuidStyle::Urn => out.push(b"urn:uuid:"),
+			UuidStyle::Hyphenated | UuidStyle::Simple => {}
+		}
+
+		let hyphens = !matches!(style, UuidStyle::Simple);
+		let mut i = 0;
+		while i < uuid.len() {
+			if hyphens && matches!(i, 4 | 6 | 8 | 10) {
+				out.push_byte(b'-');
+			}
+			out.push(&case.encode(uuid[i]));
+			i += 1;
+		}
+
+		if let UuidStyle::Braced = style {
+			out.push_byte(b'}');
+		}
+
+		self.__try_write_bytes_unchecked(out.as_bytes()) // ASCII only
+	}
+}
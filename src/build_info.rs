@@ -0,0 +1,326 @@
+//! Compile-time build information: package, version, `cfg` target and profile.
+
+use crate::buf::ConstByteBuf;
+use crate::buf::ConstByteReader;
+use crate::buf::ConstStrBuf;
+use crate::buf::LenPrefix;
+use crate::buf::ReadError;
+use crate::buf::size::decimal_len;
+use crate::parse::ParseIntError;
+
+/// Build information of a crate, see [`build_info!`](macro@crate::build_info).
+///
+/// `BuildInfo` can be packed into a binary record with [`BuildInfo::to_record`]
+/// and read back with [`BuildInfo::from_record`]. The record layout is:
+///
+/// | Field               | Encoding                                          |
+/// |---------------------|---------------------------------------------------|
+/// | `name`              | `u16` little-endian length + UTF-8 bytes          |
+/// | `version`           | `u16` little-endian length + UTF-8 bytes          |
+/// | `cfg_target`        | `u16` little-endian length + UTF-8 bytes          |
+/// | `debug`             | `u8`: `1` for debug builds, `0` for release       |
+/// | `source_date_epoch` | `u8` presence flag + `u64` little-endian seconds  |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BuildInfo<'a> {
+	/// Package name (`CARGO_PKG_NAME`).
+	pub name: &'a str,
+	/// Package version (`CARGO_PKG_VERSION`).
+	pub version: &'a str,
+	/// Target description rebuilt from `cfg`, see [`CFG_TARGET`].
+	pub cfg_target: &'a str,
+	/// `true` if built with `debug_assertions`.
+	pub debug: bool,
+	/// Reproducible build timestamp (`SOURCE_DATE_EPOCH`), if set.
+	pub source_date_epoch: Option<u64>,
+}
+
+impl<'a> BuildInfo<'a> {
+	/// Size of the fixed-width part of the record.
+	const FIXED_RECORD_LEN: usize = 3 * 2 + 1 + 1 + 8;
+
+	/// Build profile name: `"debug"` or `"release"`.
+	#[inline]
+	pub const fn profile(&self) -> &'static str {
+		match self.debug {
+			true => "debug",
+			false => "release",
+		}
+	}
+
+	/// Exact size of the binary record.
+	#[inline]
+	pub const fn record_len(&self) -> usize {
+		Self::FIXED_RECORD_LEN + self.name.len() + self.version.len() + self.cfg_target.len()
+	}
+
+	/// Exact length of [`to_banner`](Self::to_banner).
+	pub const fn banner_len(&self) -> usize {
+		let mut len = self.name.len() + " ".len() + self.version.len();
+		len += " (".len() + self.cfg_target.len() + ", ".len() + self.profile().len();
+		if let Some(epoch) = self.source_date_epoch {
+			len += ", epoch ".len() + decimal_len(epoch);
+		}
+
+		len + ")".len()
+	}
+
+	/// Formats the banner: `name version (cfg_target, profile[, epoch N])`.
+	///
+	/// Panics if `CAP` is smaller than [`banner_len`](Self::banner_len).
+	#[track_caller]
+	pub const fn to_banner<const CAP: usize>(&self) -> ConstStrBuf<CAP> {
+		if self.banner_len() > CAP {
+			ConstStrBuf::<CAP>::cold_overflow_panic();
+		}
+
+		// cannot fail: the length was checked above
+		let mut buf = ConstStrBuf::<CAP>::new();
+		buf.push_str(self.name);
+		buf.push_str(" ");
+		buf.push_str(self.version);
+		buf.push_str(" (");
+		buf.push_str(self.cfg_target);
+		buf.push_str(", ");
+		buf.push_str(self.profile());
+		if let Some(epoch) = self.source_date_epoch {
+			buf.push_str(", epoch ");
+			let _ = buf._try_push_u64(epoch);
+		}
+		buf.push_str(")");
+
+		buf
+	}
+
+	/// Packs the information into a binary record.
+	///
+	/// Panics if `CAP` is smaller than [`record_len`](Self::record_len)
+	/// or a string is longer than `u16::MAX`.
+	#[track_caller]
+	pub const fn to_record<const CAP: usize>(&self) -> ConstByteBuf<CAP> {
+		let mut buf = ConstByteBuf::<CAP>::new();
+
+		let mark = buf.begin_len_prefix(LenPrefix::U16Le);
+		buf.write_bytes(self.name.as_bytes());
+		buf.end_len_prefix(mark);
+
+		let mark = buf.begin_len_prefix(LenPrefix::U16Le);
+		buf.write_bytes(self.version.as_bytes());
+		buf.end_len_prefix(mark);
+
+		let mark = buf.begin_len_prefix(LenPrefix::U16Le);
+		buf.write_bytes(self.cfg_target.as_bytes());
+		buf.end_len_prefix(mark);
+
+		buf.push_u8(self.debug as u8);
+		match self.source_date_epoch {
+			Some(a) => {
+				buf.push_u8(1);
+				buf.push_u64_le(a);
+			}
+			None => {
+				buf.push_u8(0);
+				buf.push_u64_le(0);
+			}
+		}
+
+		buf
+	}
+
+	/// Reads back a record produced by [`to_record`](Self::to_record).
+	pub const fn from_record(data: &'a [u8]) -> Result<Self, ReadError> {
+		let mut reader = ConstByteReader::new(data);
+
+		let name = match read_len_str(&mut reader) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let version = match read_len_str(&mut reader) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let cfg_target = match read_len_str(&mut reader) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let debug = match reader.read_u8() {
+			Ok(a) => a != 0,
+			Err(e) => return Err(e),
+		};
+		let has_epoch = match reader.read_u8() {
+			Ok(a) => a != 0,
+			Err(e) => return Err(e),
+		};
+		let source_date_epoch = match reader.read_u64_le() {
+			Ok(a) if has_epoch => Some(a),
+			Ok(_) => None,
+			Err(e) => return Err(e),
+		};
+
+		Ok(Self {
+			name,
+			version,
+			cfg_target,
+			debug,
+			source_date_epoch,
+		})
+	}
+}
+
+/// Reads a `u16` little-endian length-prefixed string.
+const fn read_len_str<'a>(reader: &mut ConstByteReader<'a>) -> Result<&'a str, ReadError> {
+	let len = match reader.read_u16_le() {
+		Ok(a) => a as usize,
+		Err(e) => return Err(e),
+	};
+
+	reader.read_str(len)
+}
+
+/// Parses a `SOURCE_DATE_EPOCH` value: a non-empty string of ASCII decimal digits.
+///
+/// Unlike [`parse_u64`](crate::parse::parse_u64), no sign, radix prefix or `_`
+/// separator is accepted.
+pub const fn parse_source_date_epoch(s: &str) -> Result<u64, ParseIntError> {
+	let bytes = s.as_bytes();
+	let mut i = 0;
+	while i < bytes.len() {
+		if !bytes[i].is_ascii_digit() {
+			return Err(ParseIntError::InvalidDigit);
+		}
+		i += 1;
+	}
+
+	crate::parse::parse_u64_radix(s, 10)
+}
+
+/// Returns the first name whose condition is `true`, or `"unknown"`.
+macro_rules! cfg_name {
+	[$key:ident: $($name:literal)*] => {
+		$(
+			if cfg!($key = $name) {
+				$name
+			} else
+		)* {
+			"unknown"
+		}
+	};
+}
+
+/// Target architecture (`target_arch`).
+pub const TARGET_ARCH: &str = cfg_name! {
+	target_arch: "x86_64" "x86" "aarch64" "arm" "riscv64" "riscv32" "wasm32" "wasm64"
+	"loongarch64" "mips" "mips64" "powerpc" "powerpc64" "s390x" "sparc64" "avr" "msp430"
+	"xtensa"
+};
+
+/// Target vendor (`target_vendor`).
+pub const TARGET_VENDOR: &str = cfg_name! {
+	target_vendor: "unknown" "pc" "apple" "fortanix" "espressif" "nintendo" "sony" "wrs"
+	"uwp" "win7"
+};
+
+/// Target operating system (`target_os`).
+pub const TARGET_OS: &str = cfg_name! {
+	target_os: "linux" "windows" "macos" "ios" "android" "freebsd" "netbsd" "openbsd"
+	"dragonfly" "solaris" "illumos" "fuchsia" "redox" "hermit" "wasi" "uefi" "espidf"
+	"horizon" "vxworks" "aix" "haiku" "none"
+};
+
+/// Target environment (`target_env`), empty if unspecified.
+pub const TARGET_ENV: &str = {
+	let env = cfg_name! {
+		target_env: "gnu" "musl" "msvc" "sgx" "uclibc" "newlib" "ohos" "p1" "p2" "relibc"
+	};
+	match env.as_bytes() {
+		b"unknown" => "",
+		_ => env,
+	}
+};
+
+/// Target description rebuilt from `cfg` as `arch-vendor-os[-env]`.
+///
+/// This is not the target triple: Cargo does not expose the triple to crates, and
+/// the `cfg` values do not map back to it (e.g. `aarch64-apple-macos` for
+/// `aarch64-apple-darwin`, `arm-unknown-none` for `thumbv7em-none-eabihf`).
+/// Forward `TARGET` from a build script if the exact triple is needed.
+pub const CFG_TARGET: &str = {
+	const ENV_SEP: &str = match TARGET_ENV.is_empty() {
+		true => "",
+		false => "-",
+	};
+
+	crate::concat_str!(
+		TARGET_ARCH,
+		"-",
+		TARGET_VENDOR,
+		"-",
+		TARGET_OS,
+		ENV_SEP,
+		TARGET_ENV
+	)
+};
+
+/// Compile-time build information module.
+///
+/// Generates a module with the following constants of the calling crate:
+///
+/// | Constant            | Type                      | Source                                  |
+/// |---------------------|---------------------------|-----------------------------------------|
+/// | `PKG_NAME`          | `&str`                    | `CARGO_PKG_NAME`                        |
+/// | `PKG_VERSION`       | `&str`                    | `CARGO_PKG_VERSION`                     |
+/// | `SOURCE_DATE_EPOCH` | `Option<u64>`             | `SOURCE_DATE_EPOCH` (validated)         |
+/// | `CFG_TARGET`        | `&str`                    | [`CFG_TARGET`](crate::build_info::CFG_TARGET) |
+/// | `PROFILE`           | `&str`                    | `"debug"`/`"release"` (`debug_assertions`) |
+/// | `BANNER`            | `&str`                    | [`BuildInfo::to_banner`](crate::build_info::BuildInfo::to_banner) |
+/// | `INFO`              | [`BuildInfo`](crate::build_info::BuildInfo) | all of the above            |
+/// | `RECORD`            | `ConstByteBuf<RECORD_LEN>` | [`BuildInfo::to_record`](crate::build_info::BuildInfo::to_record) |
+///
+/// A `SOURCE_DATE_EPOCH` that is not a decimal `u64` is a compile error.
+///
+/// # Example
+/// ```rust
+/// cluConstData::build_info! {
+///	pub mod build;
+/// }
+///
+/// use cluConstData::build_info::BuildInfo;
+///
+/// assert!(build::BANNER.starts_with(env!("CARGO_PKG_NAME")));
+/// assert_eq!(BuildInfo::from_record(build::RECORD.as_bytes()), Ok(build::INFO));
+/// ```
+#[macro_export]
+macro_rules! build_info {
+	[
+		$(#[$meta:meta])*
+		$vis:vis mod $name:ident $(;)?
+	] => {
+		$(#[$meta])*
+		$vis mod $name {
+			$crate::const_data! {
+				pub const PKG_NAME: &'static str = env!("CARGO_PKG_NAME");
+				pub const PKG_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+				pub const SOURCE_DATE_EPOCH: Option<u64> = match option_env!("SOURCE_DATE_EPOCH") {
+					Some(a) => match $crate::build_info::parse_source_date_epoch(a) {
+						Ok(a) => Some(a),
+						Err(_) => panic!("environment variable `SOURCE_DATE_EPOCH` is not a decimal `u64`"),
+					},
+					None => None,
+				};
+				pub const CFG_TARGET: &'static str = $crate::build_info::CFG_TARGET;
+				pub const PROFILE: &'static str = INFO.profile();
+
+				pub const BANNER: &'static str = $crate::__buf_str!(INFO.banner_len(), INFO.to_banner());
+
+				pub const INFO: $crate::build_info::BuildInfo<'static> = $crate::build_info::BuildInfo {
+					name: PKG_NAME,
+					version: PKG_VERSION,
+					cfg_target: CFG_TARGET,
+					debug: cfg!(debug_assertions),
+					source_date_epoch: SOURCE_DATE_EPOCH,
+				};
+				pub const RECORD_LEN: usize = INFO.record_len();
+				pub const RECORD: $crate::buf::ConstByteBuf<RECORD_LEN> = INFO.to_record();
+			}
+		}
+	};
+}
@@ -17,6 +17,7 @@
 /// |-------------------------------|------------------------------------------------------|
 /// | `const NAME: T = value;`      | Defines a simple constant value                     |
 /// | `const NAME: &str = a, b, c;` | Compile-time concat via `concat_str!`               |
+/// | `const NAME: &CStr = a, b;`   | Compile-time concat via `concat_cstr!` (adds the trailing NUL) |
 /// | `const NAME: &[$T] = a, b;`   | Compile-time merges slices into new `&[$T]` via `concat_array!`  |
 /// | `const NAME: [$T; N] = a, b;` | Compile-time merges arrays into new `[T; N]`                     |
 /// | `const NAME: &[$T; N] = ...;` | Creates referenced array literal                    |
@@ -67,6 +68,19 @@ macro_rules! const_data {
 		$crate::const_data! {$($tt)*}
 	};
 
+	// concat_cstr: &'static CStr, &CStr
+	[
+		$vis:vis const $name: ident : & $($l: lifetime)? CStr = $a:expr, $($b:expr),* $(,)?;
+
+		$($tt:tt)*
+	] => {
+		$vis const $name: & $($l)? core::ffi::CStr = $crate::concat_cstr!(
+			$a, $($b),*
+		);
+
+		$crate::const_data! {$($tt)*}
+	};
+
 	// concat_array: &[T] &[T; N]
 	[
 		$vis:vis const $name: ident : &$($l: lifetime)? [$t:ty $(; $($_n:expr)?)?] = $a:expr, $($b:expr),* $(,)?;
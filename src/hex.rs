@@ -0,0 +1,144 @@
+//! Const decoding of hexadecimal strings.
+
+use core::fmt::Display;
+
+/// Error type returned by [`decode_hex`] and [`decoded_len`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDecodeError {
+	/// A character is neither a hexadecimal digit nor a separator.
+	InvalidChar,
+	/// A byte has only one digit (odd number of digits, or a separator inside a pair).
+	IncompleteByte,
+	/// The output array length does not match the number of encoded bytes.
+	LengthMismatch,
+}
+
+impl HexDecodeError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::InvalidChar => "invalid hex: unexpected character",
+			Self::IncompleteByte => "invalid hex: incomplete byte (odd number of digits)",
+			Self::LengthMismatch => "invalid hex: output length does not match",
+		}
+	}
+}
+
+impl Display for HexDecodeError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for HexDecodeError {}
+
+/// `true` for the separators allowed between bytes: ASCII whitespace and `:`.
+const fn is_separator(c: u8) -> bool {
+	c.is_ascii_whitespace() || c == b':'
+}
+
+/// Walks `s`, writing decoded bytes into `out` (if any), and returns their number.
+const fn decode_into(s: &str, mut out: Option<&mut [u8]>) -> Result<usize, HexDecodeError> {
+	let s = s.as_bytes();
+	let mut len = 0;
+	let mut high: Option<u8> = None;
+	let mut i = 0;
+	while i < s.len() {
+		let c = s[i];
+		i += 1;
+		if is_separator(c) {
+			if high.is_some() {
+				return Err(HexDecodeError::IncompleteByte);
+			}
+			continue;
+		}
+
+		let digit = match (c as char).to_digit(16) {
+			Some(a) => a as u8,
+			None => return Err(HexDecodeError::InvalidChar),
+		};
+		match high {
+			None => high = Some(digit),
+			Some(a) => {
+				if let Some(out) = &mut out {
+					if len >= out.len() {
+						return Err(HexDecodeError::LengthMismatch);
+					}
+					out[len] = a << 4 | digit;
+				}
+				len += 1;
+				high = None;
+			}
+		}
+	}
+	if high.is_some() {
+		return Err(HexDecodeError::IncompleteByte);
+	}
+
+	Ok(len)
+}
+
+/// Number of bytes encoded in `s`, validating it.
+///
+/// Bytes are pairs of hexadecimal digits (any case), optionally separated by ASCII
+/// whitespace or `:`.
+pub const fn decoded_len(s: &str) -> Result<usize, HexDecodeError> {
+	decode_into(s, None)
+}
+
+/// Decodes `s` into exactly `N` bytes.
+///
+/// Accepts the same syntax as [`decoded_len`].
+pub const fn decode_hex<const N: usize>(s: &str) -> Result<[u8; N], HexDecodeError> {
+	let mut result = [0u8; N];
+	match decode_into(s, Some(&mut result)) {
+		Ok(len) if len == N => Ok(result),
+		Ok(_) => Err(HexDecodeError::LengthMismatch),
+		Err(e) => Err(e),
+	}
+}
+
+/// Compile-time hexadecimal literal as an exact-size `[u8; N]` array.
+///
+/// Pairs of digits may be separated by ASCII whitespace or `:`; invalid input is
+/// a compile error.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::{concat_bytes, const_data, hex};
+///
+/// const MAGIC: [u8; 4] = hex!("de ad be ef");
+/// assert_eq!(MAGIC, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// const_data! {
+///	const KEY: &[u8] = &hex!("00:11:22:33"), &hex!("
+///		44 55 66 77
+///		88 99 aa bb
+///	");
+/// }
+/// assert_eq!(KEY.len(), 12);
+///
+/// const FRAME: &[u8] = concat_bytes!(&MAGIC, &hex!("0a0b"));
+/// assert_eq!(FRAME, &[0xde, 0xad, 0xbe, 0xef, 0x0a, 0x0b]);
+/// ```
+///
+/// ```rust,compile_fail
+/// const ODD: [u8; 2] = cluConstData::hex!("de a");
+/// ```
+#[macro_export]
+macro_rules! hex {
+	[$s: expr $(,)?] => {{
+		const _STR: &str = $s;
+		const _LEN: usize = match $crate::hex::decoded_len(_STR) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+		const _HIDDEN: [u8; _LEN] = match $crate::hex::decode_hex(_STR) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		_HIDDEN
+	}};
+}
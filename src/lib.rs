@@ -84,6 +84,8 @@ fn main() {
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
 
+use core::mem::MaybeUninit;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "const_buf")))]
 #[cfg(any(test, feature = "const_buf"))]
 pub mod buf;
@@ -92,6 +94,17 @@ pub mod buf;
 #[cfg(any(test, feature = "const_data"))]
 mod const_data;
 
+pub mod base64;
+pub mod hex;
+pub mod net;
+pub mod parse;
+pub mod semver;
+pub mod uuid;
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "const_buf", feature = "const_data"))))]
+#[cfg(any(test, all(feature = "const_buf", feature = "const_data")))]
+pub mod build_info;
+
 /// Concatenates two arrays into one.
 ///
 /// # Panics
@@ -117,43 +130,57 @@ where
 		_cold_panic("The array size is not enough to accommodate two arrays.");
 	}
 
-	#[cfg_attr(docsrs, doc(cfg(feature = "clufulltransmute")))]
+	let mut result: [MaybeUninit<T>; R_LEN] = [MaybeUninit::uninit(); R_LEN];
+
+	let mut i = 0usize;
+	while a_len > i {
+		result[i].write(a[i]);
+		i += 1;
+	}
+	while R_LEN > i {
+		result[i].write(b[i - a_len]);
+		i += 1;
+	}
+
+	// every element in `0..R_LEN` was written above
+	unsafe { array_assume_init(result) }
+}
+
+/// Converts a fully initialized `[MaybeUninit<T>; N]` into `[T; N]`.
+///
+/// With `clufulltransmute` the conversion goes through `cluFullTransmute`,
+/// otherwise a plain `core` pointer read is used; both are sound for any `T: Copy`.
+///
+/// # Safety
+/// Every element of `array` must be initialized.
+#[inline]
+pub(crate) const unsafe fn array_assume_init<T, const N: usize>(
+	array: [MaybeUninit<T>; N],
+) -> [T; N]
+where
+	T: Copy,
+{
 	#[cfg(feature = "clufulltransmute")]
 	{
-		use cluFullTransmute::transmute_unchecked;
-		use core::mem::MaybeUninit;
-		let mut result: [MaybeUninit<T>; R_LEN] = [MaybeUninit::uninit(); R_LEN];
-
-		let mut i = 0usize;
-		while a_len > i {
-			result[i].write(a[i]);
-			i += 1;
-		}
-		while R_LEN > i {
-			result[i].write(b[i - a_len]);
-			i += 1;
-		}
-
 		// TODO WAIT https://github.com/rust-lang/rust/issues/96097 in stable
-		unsafe { transmute_unchecked(result) }
+		unsafe { cluFullTransmute::transmute_unchecked(array) }
 	}
-	#[cfg_attr(docsrs, doc(cfg(not(feature = "clufulltransmute"))))]
 	#[cfg(not(feature = "clufulltransmute"))]
 	{
-		let mut result: [T; R_LEN] = unsafe { core::mem::zeroed() };
-
-		let mut i = 0usize;
-		while a_len > i {
-			result[i] = a[i];
-			i += 1;
-		}
-		while R_LEN > i {
-			result[i] = b[i - a_len];
-			i += 1;
-		}
+		// `[MaybeUninit<T>; N]` and `[T; N]` have the same size and layout.
+		unsafe { (&raw const array).cast::<[T; N]>().read() }
+	}
+}
 
-		result
+/// Number of decimal digits of `value`.
+pub(crate) const fn decimal_len(mut value: u64) -> usize {
+	let mut len = 1;
+	while value >= 10 {
+		value /= 10;
+		len += 1;
 	}
+
+	len
 }
 
 /// When `debug_assert` is enabled, the API is checked for correctness
@@ -167,6 +194,19 @@ pub const unsafe fn debug_validate_then_cast_str(array: &[u8]) -> &str {
 	unsafe { core::str::from_utf8_unchecked(array) }
 }
 
+/// Converts NUL-terminated bytes into a `&CStr`, panicking (a compile error in `const`)
+/// if the bytes contain an interior NUL or lack the trailing one.
+///
+/// Only for internal use in macros!
+#[doc(hidden)]
+#[track_caller]
+pub const fn cstr_from_bytes_with_nul_or_panic(array: &[u8]) -> &core::ffi::CStr {
+	match core::ffi::CStr::from_bytes_with_nul(array) {
+		Ok(a) => a,
+		Err(_) => panic!("concat_cstr!: the string contains an interior NUL byte"),
+	}
+}
+
 /// Compile-time bytes array concatenation.
 ///
 /// Recursively merges multiple slice-like values (`&[u8]`) **at compile time**,  
@@ -294,3 +334,53 @@ macro_rules! concat_str {
 		}
 	}};
 }
+
+/// Compile-time C string concatenation.
+///
+/// Concatenates multiple `&'static str` slices **at compile time** and appends the
+/// trailing NUL, producing a `&'static core::ffi::CStr`. An interior NUL byte in any of
+/// the parts is rejected at compile time.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::concat_cstr;
+/// use core::ffi::CStr;
+/// const NAME: &str = "world";
+/// const MESSAGE: &CStr = concat_cstr!("Hello, ", NAME, "!");
+/// assert_eq!(MESSAGE, c"Hello, world!");
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluConstData::concat_cstr;
+/// const MESSAGE: &core::ffi::CStr = concat_cstr!("Hello,\0", "world!");
+/// ```
+#[macro_export]
+macro_rules! concat_cstr {
+	[$a: expr $(, $b: expr)* $(,)?] => {{
+		const _STR: &str = $crate::concat_str!($a $(, $b)*);
+		const _WITH_NUL: &[u8] = $crate::concat_array! { // -> &[u8]
+			:&[u8] =
+				core::primitive::str::as_bytes(_STR),
+				b"\0"
+		};
+		const _HIDDEN: &core::ffi::CStr = $crate::cstr_from_bytes_with_nul_or_panic(_WITH_NUL);
+
+		_HIDDEN
+	}};
+}
+
+/// Evaluates a `ConstStrBuf<CAP>` expression at compile time and returns the written
+/// data as `&'static str`.
+///
+/// Only for internal use in macros!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __buf_str {
+	[$cap: expr, $buf: expr $(,)?] => {{
+		const _BUF: $crate::buf::ConstStrBuf<{ $cap }> = $buf;
+		const _ARRAY: [u8; _BUF.len()] = _BUF.__into_exact_array();
+		const _HIDDEN: &str = unsafe { $crate::debug_validate_then_cast_str(&_ARRAY) };
+
+		_HIDDEN
+	}};
+}
@@ -0,0 +1,391 @@
+//! Const parsing of IP, socket and MAC addresses.
+
+use core::fmt::Display;
+use core::net::IpAddr;
+use core::net::Ipv4Addr;
+use core::net::Ipv6Addr;
+use core::net::SocketAddr;
+use core::net::SocketAddrV4;
+use core::net::SocketAddrV6;
+
+/// Error type returned by the address parsers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddrParseError {
+	/// Invalid IPv4 address syntax.
+	Ipv4,
+	/// Invalid IPv6 address syntax.
+	Ipv6,
+	/// Invalid socket address syntax.
+	SocketAddr,
+	/// Invalid MAC address syntax.
+	Mac,
+}
+
+impl AddrParseError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ipv4 => "invalid IPv4 address syntax",
+			Self::Ipv6 => "invalid IPv6 address syntax",
+			Self::SocketAddr => "invalid socket address syntax",
+			Self::Mac => "invalid MAC address syntax",
+		}
+	}
+}
+
+impl Display for AddrParseError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for AddrParseError {}
+
+/// Reads a decimal number of up to `max_digits` digits not exceeding `max`.
+///
+/// As in `core::net`, leading zeros are rejected unless `allow_leading_zero` is set
+/// (ports and scope ids).
+const fn read_decimal(
+	s: &[u8],
+	mut pos: usize,
+	max_digits: usize,
+	max: u32,
+	allow_leading_zero: bool,
+) -> Option<(u32, usize)> {
+	let start = pos;
+	let mut value = 0u64;
+	while pos < s.len() && s[pos].is_ascii_digit() && pos - start < max_digits {
+		value = value * 10 + (s[pos] - b'0') as u64;
+		if value > max as u64 {
+			return None;
+		}
+		pos += 1;
+	}
+
+	match pos - start {
+		0 => None,
+		1 => Some((value as u32, pos)),
+		_ if s[start] == b'0' && !allow_leading_zero => None,
+		_ => Some((value as u32, pos)),
+	}
+}
+
+/// Reads `1..=4` hexadecimal digits.
+const fn read_hex_group(s: &[u8], mut pos: usize) -> Option<(u16, usize)> {
+	let start = pos;
+	let mut value = 0u16;
+	while pos < s.len() && pos - start < 4 {
+		let digit = match (s[pos] as char).to_digit(16) {
+			Some(a) => a as u16,
+			None => break,
+		};
+		value = (value << 4) | digit;
+		pos += 1;
+	}
+
+	match pos - start {
+		0 => None,
+		_ => Some((value, pos)),
+	}
+}
+
+/// Reads a dotted-decimal IPv4 address.
+const fn read_ipv4(s: &[u8], mut pos: usize) -> Option<([u8; 4], usize)> {
+	let mut octets = [0u8; 4];
+	let mut i = 0;
+	while i < 4 {
+		if i > 0 {
+			if pos >= s.len() || s[pos] != b'.' {
+				return None;
+			}
+			pos += 1;
+		}
+		match read_decimal(s, pos, 3, 255, false) {
+			Some((a, next)) => {
+				octets[i] = a as u8;
+				pos = next;
+			}
+			None => return None,
+		}
+		i += 1;
+	}
+
+	Some((octets, pos))
+}
+
+/// Reads up to `limit` colon-separated groups into `groups`.
+///
+/// An embedded IPv4 address is accepted as two groups starting at index `ipv4_from`
+/// or later, only if it ends the address (is not followed by `:`).
+///
+/// Returns the number of groups read and the position after them.
+const fn read_groups(
+	s: &[u8],
+	mut pos: usize,
+	groups: &mut [u16; 8],
+	limit: usize,
+	ipv4_from: usize,
+) -> (usize, usize) {
+	let mut i = 0;
+	while i < limit {
+		let start = pos;
+		if i > 0 {
+			if pos >= s.len() || s[pos] != b':' {
+				break;
+			}
+			pos += 1;
+		}
+
+		if i >= ipv4_from
+			&& i + 1 < limit
+			&& let Some(([a, b, c, d], next)) = read_ipv4(s, pos)
+			&& (next >= s.len() || s[next] != b':')
+		{
+			groups[i] = u16::from_be_bytes([a, b]);
+			groups[i + 1] = u16::from_be_bytes([c, d]);
+			return (i + 2, next);
+		}
+		match read_hex_group(s, pos) {
+			Some((a, next)) => {
+				groups[i] = a;
+				pos = next;
+			}
+			None => {
+				pos = start;
+				break;
+			}
+		}
+		i += 1;
+	}
+
+	(i, pos)
+}
+
+/// Reads an IPv6 address with optional `::` compression.
+const fn read_ipv6(s: &[u8], pos: usize) -> Option<(Ipv6Addr, usize)> {
+	let mut groups = [0u16; 8];
+	// the head may only end with an IPv4 address if it is the whole address
+	let (head, pos) = read_groups(s, pos, &mut groups, 8, 6);
+	if head == 8 {
+		return Some((ipv6_from_groups(groups), pos));
+	}
+
+	match s.split_at(pos) {
+		(_, [b':', b':', ..]) => {}
+		_ => return None,
+	}
+	// `::` stands for at least one zero group
+	let mut tail = [0u16; 8];
+	let (tail_len, pos) = read_groups(s, pos + 2, &mut tail, 7 - head, 0);
+
+	let mut i = 0;
+	while i < tail_len {
+		groups[8 - tail_len + i] = tail[i];
+		i += 1;
+	}
+
+	Some((ipv6_from_groups(groups), pos))
+}
+
+const fn ipv6_from_groups(g: [u16; 8]) -> Ipv6Addr {
+	Ipv6Addr::new(g[0], g[1], g[2], g[3], g[4], g[5], g[6], g[7])
+}
+
+/// Parses a dotted-decimal IPv4 address (`"10.0.0.1"`).
+pub const fn parse_ipv4(s: &str) -> Result<Ipv4Addr, AddrParseError> {
+	let s = s.as_bytes();
+	match read_ipv4(s, 0) {
+		Some(([a, b, c, d], pos)) if pos == s.len() => Ok(Ipv4Addr::new(a, b, c, d)),
+		_ => Err(AddrParseError::Ipv4),
+	}
+}
+
+/// Parses an IPv6 address (`"2001:db8::1"`, `"::ffff:10.0.0.1"`).
+pub const fn parse_ipv6(s: &str) -> Result<Ipv6Addr, AddrParseError> {
+	let s = s.as_bytes();
+	match read_ipv6(s, 0) {
+		Some((a, pos)) if pos == s.len() => Ok(a),
+		_ => Err(AddrParseError::Ipv6),
+	}
+}
+
+/// Parses an IPv4 or IPv6 address.
+pub const fn parse_ip(s: &str) -> Result<IpAddr, AddrParseError> {
+	match parse_ipv4(s) {
+		Ok(a) => Ok(IpAddr::V4(a)),
+		Err(_) => match parse_ipv6(s) {
+			Ok(a) => Ok(IpAddr::V6(a)),
+			Err(e) => Err(e),
+		},
+	}
+}
+
+/// Parses a socket address: `"10.0.0.1:80"` or `"[2001:db8::1%2]:443"`.
+pub const fn parse_socket_addr(s: &str) -> Result<SocketAddr, AddrParseError> {
+	let s = s.as_bytes();
+	let (addr, pos) = match s {
+		[b'[', ..] => {
+			let (ip, mut pos) = match read_ipv6(s, 1) {
+				Some(a) => a,
+				None => return Err(AddrParseError::SocketAddr),
+			};
+			let mut scope_id = 0;
+			if pos < s.len() && s[pos] == b'%' {
+				(scope_id, pos) = match read_decimal(s, pos + 1, usize::MAX, u32::MAX, true) {
+					Some(a) => a,
+					None => return Err(AddrParseError::SocketAddr),
+				};
+			}
+			if pos >= s.len() || s[pos] != b']' {
+				return Err(AddrParseError::SocketAddr);
+			}
+
+			(
+				SocketAddr::V6(SocketAddrV6::new(ip, 0, 0, scope_id)),
+				pos + 1,
+			)
+		}
+		_ => match read_ipv4(s, 0) {
+			Some(([a, b, c, d], pos)) => {
+				let ip = Ipv4Addr::new(a, b, c, d);
+				(SocketAddr::V4(SocketAddrV4::new(ip, 0)), pos)
+			}
+			None => return Err(AddrParseError::SocketAddr),
+		},
+	};
+	if pos >= s.len() || s[pos] != b':' {
+		return Err(AddrParseError::SocketAddr);
+	}
+
+	match read_decimal(s, pos + 1, usize::MAX, u16::MAX as u32, true) {
+		Some((port, end)) if end == s.len() => {
+			let mut addr = addr;
+			addr.set_port(port as u16);
+			Ok(addr)
+		}
+		_ => Err(AddrParseError::SocketAddr),
+	}
+}
+
+/// Parses a MAC address with `:` or `-` separators (`"00:1a:2b:3c:4d:5e"`).
+pub const fn parse_mac(s: &str) -> Result<[u8; 6], AddrParseError> {
+	let s = s.as_bytes();
+	if s.len() != 17 {
+		return Err(AddrParseError::Mac);
+	}
+
+	let separator = s[2];
+	if separator != b':' && separator != b'-' {
+		return Err(AddrParseError::Mac);
+	}
+	let mut result = [0u8; 6];
+	let mut i = 0;
+	while i < 6 {
+		let pos = i * 3;
+		if i > 0 && s[pos - 1] != separator {
+			return Err(AddrParseError::Mac);
+		}
+		match (
+			(s[pos] as char).to_digit(16),
+			(s[pos + 1] as char).to_digit(16),
+		) {
+			(Some(hi), Some(lo)) => result[i] = (hi << 4 | lo) as u8,
+			_ => return Err(AddrParseError::Mac),
+		}
+		i += 1;
+	}
+
+	Ok(result)
+}
+
+/// Parses an address at compile time, panicking (a compile error) on invalid input.
+///
+/// Only for internal use in macros!
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_addr {
+	[$ty: ty, $parse: ident, $s: expr] => {{
+		const _HIDDEN: $ty = match $crate::net::$parse($s) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		_HIDDEN
+	}};
+}
+
+/// Compile-time `Ipv4Addr`; invalid input is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use core::net::Ipv4Addr;
+/// const GATEWAY: Ipv4Addr = cluConstData::parse_ipv4!("10.0.0.1");
+/// assert_eq!(GATEWAY, Ipv4Addr::new(10, 0, 0, 1));
+/// ```
+///
+/// ```rust,compile_fail
+/// const GATEWAY: core::net::Ipv4Addr = cluConstData::parse_ipv4!("10.0.0.256");
+/// ```
+#[macro_export]
+macro_rules! parse_ipv4 {
+	[$s: expr $(,)?] => {
+		$crate::__parse_addr!(core::net::Ipv4Addr, parse_ipv4, $s)
+	};
+}
+
+/// Compile-time `Ipv6Addr`; invalid input is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use core::net::Ipv6Addr;
+/// const DNS: Ipv6Addr = cluConstData::parse_ipv6!("2001:4860:4860::8888");
+/// assert_eq!(DNS.segments()[7], 0x8888);
+/// ```
+#[macro_export]
+macro_rules! parse_ipv6 {
+	[$s: expr $(,)?] => {
+		$crate::__parse_addr!(core::net::Ipv6Addr, parse_ipv6, $s)
+	};
+}
+
+/// Compile-time `IpAddr` (IPv4 or IPv6); invalid input is a compile error.
+#[macro_export]
+macro_rules! parse_ip {
+	[$s: expr $(,)?] => {
+		$crate::__parse_addr!(core::net::IpAddr, parse_ip, $s)
+	};
+}
+
+/// Compile-time `SocketAddr`; invalid input is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use core::net::SocketAddr;
+/// const ENDPOINT: SocketAddr = cluConstData::parse_socket_addr!("[::1]:8080");
+/// assert_eq!(ENDPOINT.port(), 8080);
+/// ```
+///
+/// ```rust,compile_fail
+/// const ENDPOINT: core::net::SocketAddr = cluConstData::parse_socket_addr!("::1:8080");
+/// ```
+#[macro_export]
+macro_rules! parse_socket_addr {
+	[$s: expr $(,)?] => {
+		$crate::__parse_addr!(core::net::SocketAddr, parse_socket_addr, $s)
+	};
+}
+
+/// Compile-time MAC address as `[u8; 6]`; invalid input is a compile error.
+///
+/// # Examples
+/// ```rust
+/// const MAC: [u8; 6] = cluConstData::parse_mac!("00-1A-2B-3C-4D-5E");
+/// assert_eq!(MAC, [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+/// ```
+#[macro_export]
+macro_rules! parse_mac {
+	[$s: expr $(,)?] => {
+		$crate::__parse_addr!([u8; 6], parse_mac, $s)
+	};
+}
@@ -0,0 +1,329 @@
+//! Const parsing of integers from `&str`, including `env!` values.
+
+use core::fmt::Display;
+
+/// Error type returned by the `parse_*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseIntError {
+	/// The string contains no digits.
+	Empty,
+	/// The string contains a character that is not a digit of the radix.
+	InvalidDigit,
+	/// The value is larger than the type's maximum.
+	PosOverflow,
+	/// The value is smaller than the type's minimum.
+	NegOverflow,
+	/// The radix is not in `2..=36`.
+	InvalidRadix,
+}
+
+impl ParseIntError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Empty => "cannot parse integer from empty string",
+			Self::InvalidDigit => "invalid digit found in string",
+			Self::PosOverflow => "number too large to fit in target type",
+			Self::NegOverflow => "number too small to fit in target type",
+			Self::InvalidRadix => "radix must be in `2..=36`",
+		}
+	}
+}
+
+impl Display for ParseIntError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for ParseIntError {}
+
+/// Splits an optional leading sign, returning `(is_negative, rest)`.
+const fn split_sign(bytes: &[u8]) -> (bool, &[u8]) {
+	match bytes {
+		[b'-', rest @ ..] => (true, rest),
+		[b'+', rest @ ..] => (false, rest),
+		_ => (false, bytes),
+	}
+}
+
+/// Detects a `0x`/`0o`/`0b` prefix, returning the radix and the digits.
+const fn split_radix_prefix(bytes: &[u8]) -> (u32, &[u8]) {
+	match bytes {
+		[b'0', b'x' | b'X', rest @ ..] => (16, rest),
+		[b'0', b'o' | b'O', rest @ ..] => (8, rest),
+		[b'0', b'b' | b'B', rest @ ..] => (2, rest),
+		_ => (10, bytes),
+	}
+}
+
+/// Parses the magnitude of a number, allowing `_` separators after the first digit.
+///
+/// Returns `PosOverflow` if the magnitude exceeds `max`.
+const fn parse_magnitude(digits: &[u8], radix: u32, max: u128) -> Result<u128, ParseIntError> {
+	if radix < 2 || radix > 36 {
+		return Err(ParseIntError::InvalidRadix);
+	}
+	match digits {
+		[] => return Err(ParseIntError::Empty),
+		[b'_', ..] => return Err(ParseIntError::InvalidDigit),
+		_ => {}
+	}
+
+	let mut result = 0u128;
+	let mut i = 0;
+	while i < digits.len() {
+		let c = digits[i];
+		i += 1;
+		if c == b'_' {
+			continue;
+		}
+
+		let digit = match (c as char).to_digit(radix) {
+			Some(a) => a as u128,
+			None => return Err(ParseIntError::InvalidDigit),
+		};
+		result = match result.checked_mul(radix as u128) {
+			Some(a) => match a.checked_add(digit) {
+				Some(a) => a,
+				None => return Err(ParseIntError::PosOverflow),
+			},
+			None => return Err(ParseIntError::PosOverflow),
+		};
+		if result > max {
+			return Err(ParseIntError::PosOverflow);
+		}
+	}
+
+	Ok(result)
+}
+
+/// Parses an unsigned number with the given radix (`None` detects a prefix).
+const fn parse_unsigned(s: &str, radix: Option<u32>, max: u128) -> Result<u128, ParseIntError> {
+	let (negative, rest) = split_sign(s.as_bytes());
+	let (radix, digits) = match radix {
+		Some(a) => (a, rest),
+		None => split_radix_prefix(rest),
+	};
+
+	match parse_magnitude(digits, radix, max) {
+		Ok(0) => Ok(0), // `-0` is fine
+		Ok(_) if negative => Err(ParseIntError::NegOverflow),
+		Ok(a) => Ok(a),
+		Err(ParseIntError::PosOverflow) if negative => Err(ParseIntError::NegOverflow),
+		Err(e) => Err(e),
+	}
+}
+
+/// Parses a signed number with the given radix (`None` detects a prefix).
+///
+/// Returns the magnitude and sign; the magnitude fits `max` (or `max + 1` if negative).
+const fn parse_signed(
+	s: &str,
+	radix: Option<u32>,
+	max: u128,
+) -> Result<(bool, u128), ParseIntError> {
+	let (negative, rest) = split_sign(s.as_bytes());
+	let (radix, digits) = match radix {
+		Some(a) => (a, rest),
+		None => split_radix_prefix(rest),
+	};
+
+	let limit = if negative { max + 1 } else { max };
+	match parse_magnitude(digits, radix, limit) {
+		Ok(a) => Ok((negative, a)),
+		Err(ParseIntError::PosOverflow) if negative => Err(ParseIntError::NegOverflow),
+		Err(e) => Err(e),
+	}
+}
+
+/// Generates `parse_*`/`parse_*_radix` function pairs for integer types.
+macro_rules! impl_parse_int {
+	[
+		unsigned: $( $ty:ident => $name:ident, $name_radix:ident; )*
+	] => {
+		$(
+			#[doc = concat!("Parses `", stringify!($ty), "` from a decimal string.")]
+			///
+			/// A `0x`, `0o` or `0b` prefix selects another radix, `_` separators
+			/// are allowed between digits, and a leading `+` is accepted.
+			pub const fn $name(s: &str) -> Result<$ty, ParseIntError> {
+				match parse_unsigned(s, None, <$ty>::MAX as u128) {
+					Ok(a) => Ok(a as $ty),
+					Err(e) => Err(e),
+				}
+			}
+
+			#[doc = concat!("Parses `", stringify!($ty), "` in the given radix (`2..=36`).")]
+			///
+			/// `_` separators are allowed between digits; no prefix is accepted.
+			pub const fn $name_radix(s: &str, radix: u32) -> Result<$ty, ParseIntError> {
+				match parse_unsigned(s, Some(radix), <$ty>::MAX as u128) {
+					Ok(a) => Ok(a as $ty),
+					Err(e) => Err(e),
+				}
+			}
+		)*
+	};
+	[
+		signed: $( $ty:ident => $name:ident, $name_radix:ident; )*
+	] => {
+		$(
+			#[doc = concat!("Parses `", stringify!($ty), "` from a decimal string.")]
+			///
+			/// A `0x`, `0o` or `0b` prefix (after the sign) selects another radix,
+			/// `_` separators are allowed between digits.
+			pub const fn $name(s: &str) -> Result<$ty, ParseIntError> {
+				match parse_signed(s, None, <$ty>::MAX as u128) {
+					Ok((true, a)) => Ok((a as $ty).wrapping_neg()),
+					Ok((false, a)) => Ok(a as $ty),
+					Err(e) => Err(e),
+				}
+			}
+
+			#[doc = concat!("Parses `", stringify!($ty), "` in the given radix (`2..=36`).")]
+			///
+			/// `_` separators are allowed between digits; no prefix is accepted.
+			pub const fn $name_radix(s: &str, radix: u32) -> Result<$ty, ParseIntError> {
+				match parse_signed(s, Some(radix), <$ty>::MAX as u128) {
+					Ok((true, a)) => Ok((a as $ty).wrapping_neg()),
+					Ok((false, a)) => Ok(a as $ty),
+					Err(e) => Err(e),
+				}
+			}
+		)*
+	};
+}
+
+impl_parse_int! {
+	unsigned:
+		u8 => parse_u8, parse_u8_radix;
+		u16 => parse_u16, parse_u16_radix;
+		u32 => parse_u32, parse_u32_radix;
+		u64 => parse_u64, parse_u64_radix;
+		u128 => parse_u128, parse_u128_radix;
+		usize => parse_usize, parse_usize_radix;
+}
+
+impl_parse_int! {
+	signed:
+		i8 => parse_i8, parse_i8_radix;
+		i16 => parse_i16, parse_i16_radix;
+		i32 => parse_i32, parse_i32_radix;
+		i64 => parse_i64, parse_i64_radix;
+		i128 => parse_i128, parse_i128_radix;
+		isize => parse_isize, parse_isize_radix;
+}
+
+/// Compile-time integer from an environment variable.
+///
+/// Reads the variable with `env!` (or `option_env!` when a default is given) and
+/// parses it with the matching `parse_*` function at compile time. A missing variable
+/// without default, or a value that is not a valid integer, is a compile error.
+///
+/// Supported types: `u8`, `u16`, `u32`, `u64`, `u128`, `usize`,
+/// `i8`, `i16`, `i32`, `i64`, `i128`, `isize`.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::env_int;
+/// const MAJOR: u32 = env_int!(u32, "CARGO_PKG_VERSION_MAJOR");
+/// const BUF_SIZE: usize = env_int!(usize, "MY_BUF_SIZE_NOT_SET", default = 64);
+/// assert_eq!(BUF_SIZE, 64);
+/// ```
+///
+/// ```rust,compile_fail
+/// // error: environment variable `CARGO_PKG_NAME` contains an invalid digit for `u8`
+/// const NAME: u8 = cluConstData::env_int!(u8, "CARGO_PKG_NAME");
+/// ```
+#[macro_export]
+macro_rules! env_int {
+	[$ty:ident, $name:literal $(,)?] => {{
+		const _HIDDEN: $ty = $crate::env_int!(@parse $ty, $name, env!($name));
+
+		_HIDDEN
+	}};
+	[$ty:ident, $name:literal, default = $default:expr $(,)?] => {{
+		const _HIDDEN: $ty = match option_env!($name) {
+			Some(value) => $crate::env_int!(@parse $ty, $name, value),
+			None => $default,
+		};
+
+		_HIDDEN
+	}};
+
+	[@parse $ty:ident, $name:literal, $value:expr] => {
+		match $crate::env_int!(@fn $ty)($value) {
+			Ok(a) => a,
+			Err($crate::parse::ParseIntError::Empty) => panic!(concat!(
+				"environment variable `", $name, "` is empty, expected `", stringify!($ty), "`"
+			)),
+			Err($crate::parse::ParseIntError::InvalidDigit) => panic!(concat!(
+				"environment variable `", $name, "` contains an invalid digit for `",
+				stringify!($ty), "`"
+			)),
+			Err(
+				$crate::parse::ParseIntError::PosOverflow
+				| $crate::parse::ParseIntError::NegOverflow
+			) => panic!(concat!(
+				"environment variable `", $name, "` is out of range for `", stringify!($ty), "`"
+			)),
+			Err($crate::parse::ParseIntError::InvalidRadix) => panic!(concat!(
+				"environment variable `", $name, "` is not a valid `", stringify!($ty), "`"
+			)),
+		}
+	};
+	[@fn u8] => { $crate::parse::parse_u8 };
+	[@fn u16] => { $crate::parse::parse_u16 };
+	[@fn u32] => { $crate::parse::parse_u32 };
+	[@fn u64] => { $crate::parse::parse_u64 };
+	[@fn u128] => { $crate::parse::parse_u128 };
+	[@fn usize] => { $crate::parse::parse_usize };
+	[@fn i8] => { $crate::parse::parse_i8 };
+	[@fn i16] => { $crate::parse::parse_i16 };
+	[@fn i32] => { $crate::parse::parse_i32 };
+	[@fn i64] => { $crate::parse::parse_i64 };
+	[@fn i128] => { $crate::parse::parse_i128 };
+	[@fn isize] => { $crate::parse::parse_isize };
+}
+
+/// Compile-time `usize` from an environment variable, see [`env_int!`](crate::env_int).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::env_usize;
+/// const BUF_SIZE: usize = env_usize!("MY_BUF_SIZE_NOT_SET", default = 64);
+/// static BUF: [u8; BUF_SIZE] = [0; BUF_SIZE];
+/// assert_eq!(BUF.len(), 64);
+/// ```
+#[macro_export]
+macro_rules! env_usize {
+	[$name:literal $($tt:tt)*] => {
+		$crate::env_int!(usize, $name $($tt)*)
+	};
+}
+
+/// Compile-time `u32` from an environment variable, see [`env_int!`](crate::env_int).
+#[macro_export]
+macro_rules! env_u32 {
+	[$name:literal $($tt:tt)*] => {
+		$crate::env_int!(u32, $name $($tt)*)
+	};
+}
+
+/// Compile-time `u64` from an environment variable, see [`env_int!`](crate::env_int).
+#[macro_export]
+macro_rules! env_u64 {
+	[$name:literal $($tt:tt)*] => {
+		$crate::env_int!(u64, $name $($tt)*)
+	};
+}
+
+/// Compile-time `i64` from an environment variable, see [`env_int!`](crate::env_int).
+#[macro_export]
+macro_rules! env_i64 {
+	[$name:literal $($tt:tt)*] => {
+		$crate::env_int!(i64, $name $($tt)*)
+	};
+}
@@ -0,0 +1,410 @@
+//! Const parsing, comparison and formatting of semantic versions (SemVer 2.0.0).
+
+use crate::decimal_len;
+use core::cmp::Ordering;
+use core::fmt::Display;
+
+/// Parsed semantic version: `MAJOR.MINOR.PATCH[-PRE][+BUILD]`.
+///
+/// # Example
+/// ```rust
+/// use cluConstData::const_semver;
+/// use cluConstData::semver::Semver;
+///
+/// const VERSION: Semver = const_semver!("1.4.2-beta.1+build.5");
+/// const _: () = assert!(VERSION.ge(&const_semver!("1.4.0")));
+///
+/// assert_eq!(VERSION.major, 1);
+/// assert_eq!(VERSION.pre, "beta.1");
+/// assert_eq!(VERSION.build, "build.5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Semver<'a> {
+	/// Major version.
+	pub major: u64,
+	/// Minor version.
+	pub minor: u64,
+	/// Patch version.
+	pub patch: u64,
+	/// Pre-release identifiers without the leading `-` (empty if absent).
+	pub pre: &'a str,
+	/// Build metadata without the leading `+` (empty if absent).
+	pub build: &'a str,
+}
+
+impl<'a> Semver<'a> {
+	/// Creates a release version without pre-release or build metadata.
+	#[inline]
+	pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+		Self {
+			major,
+			minor,
+			patch,
+			pre: "",
+			build: "",
+		}
+	}
+
+	/// Parses a version string.
+	pub const fn parse(s: &'a str) -> Result<Self, SemverError> {
+		let bytes = s.as_bytes();
+
+		let (major, pos) = match parse_num(bytes, 0) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let pos = match expect_dot(bytes, pos) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let (minor, pos) = match parse_num(bytes, pos) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let pos = match expect_dot(bytes, pos) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+		let (patch, pos) = match parse_num(bytes, pos) {
+			Ok(a) => a,
+			Err(e) => return Err(e),
+		};
+
+		// pre: `-` up to `+` or end
+		let (_, rest) = s.split_at(pos);
+		let (pre, rest) = match rest.as_bytes() {
+			[b'-', ..] => {
+				let (_, rest) = rest.split_at(1);
+				let end = find_byte(rest.as_bytes(), b'+');
+				let (pre, rest) = rest.split_at(end);
+				if let Err(e) = validate_idents(pre.as_bytes(), true) {
+					return Err(e);
+				}
+
+				(pre, rest)
+			}
+			_ => ("", rest),
+		};
+		let build = match rest.as_bytes() {
+			[] => "",
+			[b'+', ..] => {
+				let (_, build) = rest.split_at(1);
+				if let Err(e) = validate_idents(build.as_bytes(), false) {
+					return Err(e);
+				}
+
+				build
+			}
+			_ => return Err(SemverError::InvalidChar),
+		};
+
+		Ok(Self {
+			major,
+			minor,
+			patch,
+			pre,
+			build,
+		})
+	}
+
+	/// Compares precedence as defined by SemVer; build metadata is ignored.
+	pub const fn cmp_precedence(&self, other: &Semver<'_>) -> Ordering {
+		match cmp_u64(self.major, other.major) {
+			Ordering::Equal => {}
+			a => return a,
+		}
+		match cmp_u64(self.minor, other.minor) {
+			Ordering::Equal => {}
+			a => return a,
+		}
+		match cmp_u64(self.patch, other.patch) {
+			Ordering::Equal => {}
+			a => return a,
+		}
+
+		// a version without pre-release has higher precedence
+		match (self.pre.is_empty(), other.pre.is_empty()) {
+			(true, true) => Ordering::Equal,
+			(true, false) => Ordering::Greater,
+			(false, true) => Ordering::Less,
+			(false, false) => cmp_pre(self.pre.as_bytes(), other.pre.as_bytes()),
+		}
+	}
+
+	/// Equal precedence (build metadata is ignored).
+	#[inline]
+	pub const fn eq_precedence(&self, other: &Semver<'_>) -> bool {
+		matches!(self.cmp_precedence(other), Ordering::Equal)
+	}
+
+	/// Lower precedence than `other`.
+	#[inline]
+	pub const fn lt(&self, other: &Semver<'_>) -> bool {
+		matches!(self.cmp_precedence(other), Ordering::Less)
+	}
+
+	/// Lower or equal precedence than `other`.
+	#[inline]
+	pub const fn le(&self, other: &Semver<'_>) -> bool {
+		!self.gt(other)
+	}
+
+	/// Higher precedence than `other`.
+	#[inline]
+	pub const fn gt(&self, other: &Semver<'_>) -> bool {
+		matches!(self.cmp_precedence(other), Ordering::Greater)
+	}
+
+	/// Higher or equal precedence than `other`.
+	#[inline]
+	pub const fn ge(&self, other: &Semver<'_>) -> bool {
+		!self.lt(other)
+	}
+}
+
+impl Semver<'_> {
+	/// Exact number of bytes produced when formatting this version.
+	pub const fn formatted_len(&self) -> usize {
+		let mut len = decimal_len(self.major) + 1 + decimal_len(self.minor) + 1;
+		len += decimal_len(self.patch);
+		if !self.pre.is_empty() {
+			len += 1 + self.pre.len();
+		}
+		if !self.build.is_empty() {
+			len += 1 + self.build.len();
+		}
+
+		len
+	}
+}
+
+impl Display for Semver<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+		if !self.pre.is_empty() {
+			write!(f, "-{}", self.pre)?;
+		}
+		if !self.build.is_empty() {
+			write!(f, "+{}", self.build)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Error type returned by `Semver::parse`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemverError {
+	/// The input ended where a number was expected.
+	UnexpectedEnd,
+	/// An unexpected character was found.
+	InvalidChar,
+	/// A numeric component has a leading zero.
+	LeadingZero,
+	/// A numeric component does not fit into `u64`.
+	Overflow,
+	/// A pre-release or build identifier is empty.
+	EmptyIdentifier,
+}
+
+impl SemverError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::UnexpectedEnd => "invalid version: unexpected end of input",
+			Self::InvalidChar => "invalid version: unexpected character",
+			Self::LeadingZero => "invalid version: numeric component has a leading zero",
+			Self::Overflow => "invalid version: numeric component does not fit into u64",
+			Self::EmptyIdentifier => "invalid version: empty identifier",
+		}
+	}
+}
+
+impl Display for SemverError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for SemverError {}
+
+/// Parses a numeric component starting at `pos`, returning it and the next position.
+const fn parse_num(bytes: &[u8], mut pos: usize) -> Result<(u64, usize), SemverError> {
+	let start = pos;
+	let mut result = 0u64;
+	while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+		result = match result.checked_mul(10) {
+			Some(a) => match a.checked_add((bytes[pos] - b'0') as u64) {
+				Some(a) => a,
+				None => return Err(SemverError::Overflow),
+			},
+			None => return Err(SemverError::Overflow),
+		};
+		pos += 1;
+	}
+
+	match pos - start {
+		0 if pos >= bytes.len() => Err(SemverError::UnexpectedEnd),
+		0 => Err(SemverError::InvalidChar),
+		1 => Ok((result, pos)),
+		_ if bytes[start] == b'0' => Err(SemverError::LeadingZero),
+		_ => Ok((result, pos)),
+	}
+}
+
+/// Expects a `.` at `pos`, returning the next position.
+const fn expect_dot(bytes: &[u8], pos: usize) -> Result<usize, SemverError> {
+	if pos >= bytes.len() {
+		return Err(SemverError::UnexpectedEnd);
+	}
+	if bytes[pos] != b'.' {
+		return Err(SemverError::InvalidChar);
+	}
+
+	Ok(pos + 1)
+}
+
+/// Returns the position of the first `byte`, or `bytes.len()`.
+const fn find_byte(bytes: &[u8], byte: u8) -> usize {
+	let mut i = 0;
+	while i < bytes.len() && bytes[i] != byte {
+		i += 1;
+	}
+
+	i
+}
+
+/// Validates dot-separated `[0-9A-Za-z-]` identifiers.
+///
+/// With `strict_numeric`, purely numeric identifiers must not have leading zeros.
+const fn validate_idents(bytes: &[u8], strict_numeric: bool) -> Result<(), SemverError> {
+	let mut start = 0;
+	let mut i = 0;
+	while i <= bytes.len() {
+		if i == bytes.len() || bytes[i] == b'.' {
+			let (_, tail) = bytes.split_at(start);
+			let (ident, _) = tail.split_at(i - start);
+			match ident {
+				[] => return Err(SemverError::EmptyIdentifier),
+				[b'0', _, ..] if strict_numeric && is_numeric(ident) => {
+					return Err(SemverError::LeadingZero);
+				}
+				_ => {}
+			}
+			start = i + 1;
+		} else if !(bytes[i].is_ascii_alphanumeric() || bytes[i] == b'-') {
+			return Err(SemverError::InvalidChar);
+		}
+		i += 1;
+	}
+
+	Ok(())
+}
+
+/// `true` if the identifier consists only of ASCII digits.
+const fn is_numeric(ident: &[u8]) -> bool {
+	let mut i = 0;
+	while i < ident.len() {
+		if !ident[i].is_ascii_digit() {
+			return false;
+		}
+		i += 1;
+	}
+
+	true
+}
+
+/// Compares two `u64` values.
+const fn cmp_u64(a: u64, b: u64) -> Ordering {
+	if a < b {
+		Ordering::Less
+	} else if a > b {
+		Ordering::Greater
+	} else {
+		Ordering::Equal
+	}
+}
+
+/// Compares two byte strings lexicographically.
+const fn cmp_bytes(a: &[u8], b: &[u8]) -> Ordering {
+	let mut i = 0;
+	while i < a.len() && i < b.len() {
+		if a[i] != b[i] {
+			return cmp_u64(a[i] as u64, b[i] as u64);
+		}
+		i += 1;
+	}
+
+	cmp_u64(a.len() as u64, b.len() as u64)
+}
+
+/// Compares pre-release identifiers as defined by SemVer.
+const fn cmp_pre(a: &[u8], b: &[u8]) -> Ordering {
+	let mut a_rest = a;
+	let mut b_rest = b;
+	loop {
+		match (a_rest.is_empty(), b_rest.is_empty()) {
+			(true, true) => return Ordering::Equal,
+			(true, false) => return Ordering::Less,
+			(false, true) => return Ordering::Greater,
+			(false, false) => {}
+		}
+
+		let (a_ident, a_tail) = a_rest.split_at(find_byte(a_rest, b'.'));
+		let (b_ident, b_tail) = b_rest.split_at(find_byte(b_rest, b'.'));
+		let order = match (is_numeric(a_ident), is_numeric(b_ident)) {
+			// no leading zeros: a longer number is a larger number
+			(true, true) => match cmp_u64(a_ident.len() as u64, b_ident.len() as u64) {
+				Ordering::Equal => cmp_bytes(a_ident, b_ident),
+				a => a,
+			},
+			(true, false) => Ordering::Less,
+			(false, true) => Ordering::Greater,
+			(false, false) => cmp_bytes(a_ident, b_ident),
+		};
+		if !matches!(order, Ordering::Equal) {
+			return order;
+		}
+
+		a_rest = skip_dot(a_tail);
+		b_rest = skip_dot(b_tail);
+	}
+}
+
+/// Drops a leading `.`, if any.
+const fn skip_dot(bytes: &[u8]) -> &[u8] {
+	match bytes {
+		[b'.', rest @ ..] => rest,
+		_ => bytes,
+	}
+}
+
+/// Compile-time semantic version.
+///
+/// Parses the string with [`Semver::parse`](crate::semver::Semver::parse) at compile time;
+/// an invalid version is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::const_semver;
+/// use cluConstData::semver::Semver;
+///
+/// const VERSION: Semver = const_semver!(env!("CARGO_PKG_VERSION"));
+/// const _: () = assert!(VERSION.ge(&Semver::new(2, 0, 0)));
+/// ```
+///
+/// ```rust,compile_fail
+/// const VERSION: cluConstData::semver::Semver = cluConstData::const_semver!("1.02.0");
+/// ```
+#[macro_export]
+macro_rules! const_semver {
+	[$s: expr $(,)?] => {{
+		const _HIDDEN: $crate::semver::Semver<'static> = match $crate::semver::Semver::parse($s) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		_HIDDEN
+	}};
+}
@@ -0,0 +1,122 @@
+//! Const parsing of UUIDs.
+
+use core::fmt::Display;
+
+/// Error type returned by [`parse_uuid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UuidParseError {
+	/// The length does not match any supported format.
+	InvalidLength,
+	/// A character is not a hexadecimal digit.
+	InvalidChar,
+	/// Hyphens, braces or the `urn:uuid:` prefix are misplaced.
+	InvalidGroup,
+}
+
+impl UuidParseError {
+	/// Returns a message describing the error (usable in `const` panics).
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::InvalidLength => "invalid UUID: unexpected length",
+			Self::InvalidChar => "invalid UUID: invalid hexadecimal digit",
+			Self::InvalidGroup => "invalid UUID: misplaced hyphen, brace or prefix",
+		}
+	}
+}
+
+impl Display for UuidParseError {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::error::Error for UuidParseError {}
+
+/// Parses a UUID into its 16 bytes (big-endian, as written).
+///
+/// Accepts the simple (`550e8400e29b41d4a716446655440000`), hyphenated
+/// (`550e8400-e29b-41d4-a716-446655440000`), braced (`{...}`) and URN
+/// (`urn:uuid:...`) forms in any letter case.
+pub const fn parse_uuid(s: &str) -> Result<[u8; 16], UuidParseError> {
+	let bytes = s.as_bytes();
+	let hyphenated = match bytes.len() {
+		32 => return decode_simple(bytes),
+		36 => bytes,
+		38 => match bytes {
+			[b'{', inner @ .., b'}'] => inner,
+			_ => return Err(UuidParseError::InvalidGroup),
+		},
+		45 => match bytes.split_at(9) {
+			(b"urn:uuid:", inner) => inner,
+			_ => return Err(UuidParseError::InvalidGroup),
+		},
+		_ => return Err(UuidParseError::InvalidLength),
+	};
+
+	let mut simple = [0u8; 32];
+	let mut len = 0;
+	let mut i = 0;
+	while i < hyphenated.len() {
+		let is_hyphen_pos = matches!(i, 8 | 13 | 18 | 23); // 8-4-4-4-12
+		match (hyphenated[i], is_hyphen_pos) {
+			(b'-', true) => {}
+			(b'-', false) | (_, true) => return Err(UuidParseError::InvalidGroup),
+			(a, false) => {
+				simple[len] = a;
+				len += 1;
+			}
+		}
+		i += 1;
+	}
+
+	decode_simple(&simple)
+}
+
+/// Decodes 32 hexadecimal digits.
+const fn decode_simple(digits: &[u8]) -> Result<[u8; 16], UuidParseError> {
+	let mut result = [0u8; 16];
+	let mut i = 0;
+	while i < 16 {
+		match (
+			(digits[i * 2] as char).to_digit(16),
+			(digits[i * 2 + 1] as char).to_digit(16),
+		) {
+			(Some(hi), Some(lo)) => result[i] = (hi << 4 | lo) as u8,
+			_ => return Err(UuidParseError::InvalidChar),
+		}
+		i += 1;
+	}
+
+	Ok(result)
+}
+
+/// Compile-time UUID as `[u8; 16]`; invalid input is a compile error.
+///
+/// Accepts every form supported by [`parse_uuid`](crate::uuid::parse_uuid).
+///
+/// # Examples
+/// ```rust
+/// use cluConstData::uuid_bytes;
+///
+/// const SERVICE: [u8; 16] = uuid_bytes!("550e8400-e29b-41d4-a716-446655440000");
+/// assert_eq!(SERVICE[..4], [0x55, 0x0e, 0x84, 0x00]);
+///
+/// const PACKET: &[u8] = cluConstData::concat_bytes!(&[0x01], &SERVICE);
+/// assert_eq!(PACKET.len(), 17);
+/// ```
+///
+/// ```rust,compile_fail
+/// const SERVICE: [u8; 16] = cluConstData::uuid_bytes!("550e8400-e29b-41d4-a716-44665544000g");
+/// ```
+#[macro_export]
+macro_rules! uuid_bytes {
+	[$s: expr $(,)?] => {{
+		const _HIDDEN: [u8; 16] = match $crate::uuid::parse_uuid($s) {
+			Ok(a) => a,
+			Err(e) => panic!("{}", e.as_str()),
+		};
+
+		_HIDDEN
+	}};
+}
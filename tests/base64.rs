@@ -0,0 +1,142 @@
+use cluConstData::base64::{Base64DecodeError, Base64Style, decode_base64, decoded_len};
+use cluConstData::base64_decode;
+
+/// RFC 4648 test vectors.
+const VECTORS: [(&str, &str); 7] = [
+	("", ""),
+	("f", "Zg=="),
+	("fo", "Zm8="),
+	("foo", "Zm9v"),
+	("foob", "Zm9vYg=="),
+	("fooba", "Zm9vYmE="),
+	("foobar", "Zm9vYmFy"),
+];
+
+#[test]
+fn decode() {
+	for (plain, encoded) in VECTORS {
+		assert_eq!(decoded_len(encoded, Base64Style::Standard), Ok(plain.len()));
+		let unpadded = encoded.trim_end_matches('=');
+		assert_eq!(
+			decoded_len(unpadded, Base64Style::Standard),
+			Ok(plain.len())
+		);
+	}
+	assert_eq!(
+		decode_base64::<6>("Zm9v\n YmFy\n", Base64Style::Standard),
+		Ok(*b"foobar")
+	);
+	assert_eq!(
+		decode_base64::<2>("-_8", Base64Style::UrlSafeNoPad),
+		Ok([0xfb, 0xff])
+	);
+
+	use Base64DecodeError::*;
+	assert_eq!(decoded_len("-_8=", Base64Style::Standard), Err(InvalidChar));
+	assert_eq!(decoded_len("+/8=", Base64Style::UrlSafe), Err(InvalidChar));
+	assert_eq!(decoded_len("Z", Base64Style::Standard), Err(InvalidLength));
+	assert_eq!(
+		decoded_len("Zg=", Base64Style::Standard),
+		Err(InvalidLength)
+	);
+	assert_eq!(
+		decoded_len("Zg===", Base64Style::Standard),
+		Err(InvalidLength)
+	);
+	assert_eq!(
+		decoded_len("Zm9v=", Base64Style::Standard),
+		Err(InvalidLength)
+	);
+	assert_eq!(
+		decoded_len("Zg==Zg==", Base64Style::Standard),
+		Err(InvalidLength)
+	);
+	assert_eq!(
+		decoded_len("Zh==", Base64Style::Standard),
+		Err(NonCanonical)
+	);
+	assert_eq!(
+		decoded_len("Zm9=", Base64Style::Standard),
+		Err(NonCanonical)
+	);
+	assert_eq!(
+		decode_base64::<2>("Zm9v", Base64Style::Standard),
+		Err(LengthMismatch)
+	);
+	assert_eq!(
+		decode_base64::<4>("Zm9v", Base64Style::Standard),
+		Err(LengthMismatch)
+	);
+}
+
+#[test]
+fn decode_macro() {
+	const TOKEN: [u8; 6] = base64_decode!("Zm9vYmFy");
+	const ID: [u8; 2] = base64_decode!("-_8", UrlSafe);
+	const EMPTY: [u8; 0] = base64_decode!("");
+
+	assert_eq!(&TOKEN, b"foobar");
+	assert_eq!(ID, [0xfb, 0xff]);
+	assert_eq!(EMPTY, []);
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_base64 {
+	use super::VECTORS;
+	use cluConstData::base64::{Base64Style, decode_base64};
+	use cluConstData::base64_str;
+	use cluConstData::buf::ConstStrBuf;
+
+	#[test]
+	fn push_base64() {
+		for (plain, encoded) in VECTORS {
+			let mut buf = ConstStrBuf::<16>::new();
+			assert_eq!(
+				buf.push_base64(plain.as_bytes(), Base64Style::Standard),
+				encoded.len()
+			);
+			assert_eq!(buf, encoded);
+
+			buf.clear();
+			buf.push_base64(plain.as_bytes(), Base64Style::StandardNoPad);
+			assert_eq!(buf, encoded.trim_end_matches('='));
+		}
+
+		fn roundtrip<const N: usize>() {
+			let data: [u8; N] = core::array::from_fn(|i| (255 - i) as u8);
+			for style in [
+				Base64Style::Standard,
+				Base64Style::StandardNoPad,
+				Base64Style::UrlSafe,
+				Base64Style::UrlSafeNoPad,
+			] {
+				let mut buf = ConstStrBuf::<344>::new();
+				assert_eq!(buf.push_base64(&data, style), style.encoded_len(N));
+				assert_eq!(decode_base64::<N>(&buf, style), Ok(data), "{buf}");
+			}
+		}
+		roundtrip::<0>();
+		roundtrip::<1>();
+		roundtrip::<2>();
+		roundtrip::<3>();
+		roundtrip::<4>();
+		roundtrip::<5>();
+		roundtrip::<64>();
+		roundtrip::<255>();
+
+		let mut small = ConstStrBuf::<3>::new();
+		assert!(small.try_push_base64(b"f", Base64Style::Standard).is_err());
+		assert!(small.is_empty());
+	}
+
+	#[test]
+	fn base64_str_macro() {
+		const CERT: &[u8] = b"foobar";
+		const ENCODED: &str = base64_str!(CERT);
+		const DECODED: [u8; 6] = cluConstData::base64_decode!(ENCODED);
+
+		assert_eq!(ENCODED, "Zm9vYmFy");
+		assert_eq!(&DECODED, CERT);
+		assert_eq!(base64_str!(*b"fo", UrlSafeNoPad), "Zm8");
+	}
+}
@@ -0,0 +1,219 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_bin {
+	use cluConstData::buf::ConstByteBuf;
+	use cluConstData::buf::size::ConstByteBufLeb128Size;
+
+	#[test]
+	fn fixed_width() {
+		const fn header() -> ConstByteBuf<32> {
+			let mut buf = ConstByteBuf::<32>::new();
+			buf.push_u8(0xAA);
+			buf.push_u16_be(0x0102);
+			buf.push_u32_le(0x0304_0506);
+			buf.push_i16_le(-2);
+			buf.push_f32_be(1.0);
+			buf.push_u64_be(7);
+			buf
+		}
+		const HEADER: ConstByteBuf<32> = header();
+
+		assert_eq!(
+			HEADER,
+			&[
+				0xAA, 0x01, 0x02, 0x06, 0x05, 0x04, 0x03, 0xFE, 0xFF, 0x3F, 0x80, 0x00, 0x00, 0, 0,
+				0, 0, 0, 0, 0, 7
+			][..]
+		);
+
+		let mut buf = ConstByteBuf::<3>::new();
+		assert!(buf.try_push_u32_le(1).is_err());
+		assert!(buf.is_empty());
+		assert_eq!(buf.try_push_i16_be(-1), Ok(2));
+		assert_eq!(buf, &[0xFF, 0xFF][..]);
+	}
+
+	#[test]
+	fn leb128() {
+		fn uleb(value: u64) -> ConstByteBuf<{ u64::MAX_LEB128_LEN }> {
+			let mut buf = ConstByteBuf::<{ u64::MAX_LEB128_LEN }>::new();
+			buf.push_uleb128(value);
+			buf
+		}
+		fn sleb(value: i64) -> ConstByteBuf<{ i64::MAX_LEB128_LEN }> {
+			let mut buf = ConstByteBuf::<{ i64::MAX_LEB128_LEN }>::new();
+			buf.push_sleb128(value);
+			buf
+		}
+		fn zigzag(value: i64) -> ConstByteBuf<{ i64::MAX_LEB128_LEN }> {
+			let mut buf = ConstByteBuf::<{ i64::MAX_LEB128_LEN }>::new();
+			buf.push_zigzag(value);
+			buf
+		}
+
+		assert_eq!(uleb(0), &[0x00][..]);
+		assert_eq!(uleb(127), &[0x7F][..]);
+		assert_eq!(uleb(624485), &[0xE5, 0x8E, 0x26][..]);
+		assert_eq!(uleb(u64::MAX).len(), u64::MAX_LEB128_LEN);
+
+		assert_eq!(sleb(0), &[0x00][..]);
+		assert_eq!(sleb(-1), &[0x7F][..]);
+		assert_eq!(sleb(63), &[0x3F][..]);
+		assert_eq!(sleb(64), &[0xC0, 0x00][..]);
+		assert_eq!(sleb(-123456), &[0xC0, 0xBB, 0x78][..]);
+		assert_eq!(sleb(i64::MIN).len(), i64::MAX_LEB128_LEN);
+
+		assert_eq!(zigzag(0), &[0][..]);
+		assert_eq!(zigzag(-1), &[1][..]);
+		assert_eq!(zigzag(1), &[2][..]);
+		assert_eq!(zigzag(-64), &[127][..]);
+		assert_eq!(zigzag(i64::MIN).len(), i64::MAX_LEB128_LEN);
+
+		let mut buf = ConstByteBuf::<2>::new();
+		assert!(buf.try_push_uleb128(624485).is_err());
+		assert!(buf.is_empty());
+	}
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_placeholder {
+	use cluConstData::buf::{ConstByteBuf, LenPrefix, LenTooLarge};
+
+	#[test]
+	fn reserve_and_fill() {
+		const fn packet() -> ConstByteBuf<16> {
+			let mut buf = ConstByteBuf::<16>::new();
+			buf.push_u8(0x01); // type
+			let len = buf.reserve::<2>();
+			let crc = buf.reserve::<4>();
+			buf.write_bytes(b"abc");
+
+			buf.fill_u16_be(len, 3);
+			buf.fill_u32_le(crc, 0xDEAD_BEEF);
+			buf
+		}
+		const PACKET: ConstByteBuf<16> = packet();
+
+		assert_eq!(
+			PACKET,
+			&[0x01, 0x00, 0x03, 0xEF, 0xBE, 0xAD, 0xDE, b'a', b'b', b'c'][..]
+		);
+
+		let mut buf = ConstByteBuf::<2>::new();
+		assert!(buf.try_reserve::<4>().is_err());
+		assert!(buf.is_empty());
+
+		let mut buf = ConstByteBuf::<16>::new();
+		let offset = buf.reserve::<2>();
+		let delta = buf.reserve::<4>();
+		let tiny = buf.reserve::<1>();
+		buf.fill_i16_be(offset, -2);
+		buf.fill_i32_le(delta, i32::MIN);
+		buf.fill_i8(tiny, -1);
+		assert_eq!(buf, &[0xFF, 0xFE, 0x00, 0x00, 0x00, 0x80, 0xFF][..]);
+	}
+
+	#[test]
+	fn len_prefix() {
+		const fn tlv() -> ConstByteBuf<16> {
+			let mut buf = ConstByteBuf::<16>::new();
+			buf.push_u8(0x10); // tag
+			let outer = buf.begin_len_prefix(LenPrefix::U16Be);
+			let pascal = buf.begin_len_prefix(LenPrefix::U8);
+			buf.push_str("hi");
+			buf.end_len_prefix(pascal);
+			buf.push_u8(0xFF);
+			buf.end_len_prefix(outer);
+			buf
+		}
+		const TLV: ConstByteBuf<16> = tlv();
+		assert_eq!(TLV, &[0x10, 0x00, 0x04, 0x02, b'h', b'i', 0xFF][..]);
+
+		let mut buf = ConstByteBuf::<300>::new();
+		let len = buf.with_len_prefix(LenPrefix::U32Le, |buf| {
+			buf.write_bytes(b"body");
+		});
+		assert_eq!(len, 4);
+		assert_eq!(buf, &[4, 0, 0, 0, b'b', b'o', b'd', b'y'][..]);
+
+		buf.clear();
+		let mark = buf.begin_len_prefix(LenPrefix::U8);
+		buf.write_bytes(&[0; 256]);
+		assert_eq!(buf.try_end_len_prefix(mark), Err(LenTooLarge));
+	}
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_reader {
+	use cluConstData::buf::{ConstByteBuf, ConstByteReader, ReadError};
+
+	const BLOB: ConstByteBuf<64> = {
+		let mut buf = ConstByteBuf::<64>::new();
+		buf.push_u8(7);
+		buf.push_u32_le(0xDEAD_BEEF);
+		buf.push_i16_be(-2);
+		buf.push_uleb128(624485);
+		buf.push_sleb128(-123456);
+		buf.push_zigzag(-64);
+		buf.push_f64_le(0.5);
+		buf.write_bytes(b"name");
+		buf
+	};
+
+	const _: () = {
+		let mut reader = BLOB.reader();
+		assert!(matches!(reader.read_u8(), Ok(7)));
+		assert!(matches!(reader.read_u32_le(), Ok(0xDEAD_BEEF)));
+	};
+
+	#[test]
+	fn read_back() {
+		let mut reader = BLOB.reader();
+		assert_eq!(reader.read_u8(), Ok(7));
+		assert_eq!(reader.read_u32_le(), Ok(0xDEAD_BEEF));
+		assert_eq!(reader.read_i16_be(), Ok(-2));
+		assert_eq!(reader.read_uleb128(), Ok(624485));
+		assert_eq!(reader.read_sleb128(), Ok(-123456));
+		assert_eq!(reader.read_zigzag(), Ok(-64));
+		assert_eq!(reader.read_f64_le(), Ok(0.5));
+		assert_eq!(reader.peek_u8(), Ok(b'n'));
+		assert_eq!(reader.remaining(), 4);
+		assert_eq!(reader.read_str(5), Err(ReadError::UnexpectedEof));
+		assert_eq!(reader.remaining(), 4);
+		assert_eq!(reader.skip(1), Ok(()));
+		assert_eq!(reader.read_str(3), Ok("ame"));
+		assert!(reader.is_empty());
+		assert_eq!(reader.read_u8(), Err(ReadError::UnexpectedEof));
+	}
+
+	#[test]
+	fn read_errors() {
+		let mut reader = ConstByteReader::new(&[0xff, 0xfe]);
+		assert!(matches!(reader.read_str(2), Err(ReadError::Utf8(_))));
+		assert_eq!(reader.pos(), 0);
+		assert_eq!(reader.read_bytes(2), Ok(&[0xff, 0xfe][..]));
+
+		// truncated LEB128
+		let mut reader = ConstByteReader::new(&[0x80, 0x80]);
+		assert_eq!(reader.read_uleb128(), Err(ReadError::UnexpectedEof));
+		assert_eq!(reader.pos(), 0);
+
+		// 65-bit value
+		let too_large = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+		assert_eq!(
+			ConstByteReader::new(&too_large).read_uleb128(),
+			Err(ReadError::Leb128Overflow)
+		);
+		let max = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+		assert_eq!(ConstByteReader::new(&max).read_uleb128(), Ok(u64::MAX));
+
+		let mut buf = ConstByteBuf::<10>::new();
+		buf.push_sleb128(i64::MIN);
+		assert_eq!(buf.reader().read_sleb128(), Ok(i64::MIN));
+		buf.clear();
+		buf.push_sleb128(i64::MAX);
+		assert_eq!(buf.reader().read_sleb128(), Ok(i64::MAX));
+		buf.clear();
+		buf.push_zigzag(i64::MIN);
+		assert_eq!(buf.reader().read_zigzag(), Ok(i64::MIN));
+	}
+}
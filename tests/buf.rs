@@ -132,4 +132,304 @@ mod test_const_buf {
 			assert_eq!(w, isize::MIN.to_string().as_bytes());
 		}
 	}
+
+	#[test]
+	fn std_traits() {
+		use cluConstData::buf::ConstByteBuf;
+		use std::collections::{BTreeSet, HashMap};
+
+		let a: ConstStrBuf<8> = "b".parse().unwrap();
+		let b = ConstStrBuf::<8>::try_from("a").unwrap();
+		assert!(ConstStrBuf::<2>::try_from("abc").is_err());
+
+		// Copy + Ord
+		let mut list = [a, b];
+		list.sort();
+		assert_eq!(list[0], "a");
+		assert_eq!(a, "b");
+
+		// Deref<Target = str> + Borrow<str>
+		assert!(a.starts_with('b'));
+		let mut map = HashMap::new();
+		map.insert(a, 1);
+		assert_eq!(map.get("b"), Some(&1));
+
+		let set: BTreeSet<ConstStrBuf<8>> = [a, b].into_iter().collect();
+		assert!(set.contains("a"));
+
+		// Extend + FromIterator
+		let mut s: ConstStrBuf<16> = ['h', 'i'].into_iter().collect();
+		s.extend([", ", "world"]);
+		assert_eq!(s, "hi, world");
+
+		let mut raw: ConstByteBuf<8> = [1u8, 2].iter().collect();
+		raw.extend([3u8]);
+		assert_eq!(&*raw, &[1, 2, 3]);
+		assert_eq!(AsRef::<[u8]>::as_ref(&raw), &[1, 2, 3]);
+
+		// lossy Display for DefBuf
+		let raw = ConstByteBuf::<8>::try_from(&b"ab\xffc"[..]).unwrap();
+		assert_eq!(raw.to_string(), "ab\u{FFFD}c");
+
+		// Hash + Borrow<[u8]> for DefBuf, Hash for downstream markers
+		let mut map = HashMap::new();
+		map.insert(raw, 2);
+		assert_eq!(map.get(&b"ab\xffc"[..]), Some(&2));
+
+		enum CustomBuf {}
+		impl cluConstData::buf::ConstByteBufData for CustomBuf {}
+		let mut custom = HashMap::new();
+		custom.insert(ConstByteBuf::<8, CustomBuf>::new(), 3);
+		assert_eq!(custom.len(), 1);
+	}
+
+	#[test]
+	fn const_constructors() {
+		use cluConstData::buf::{ConstByteBuf, FromBytesError};
+
+		const S: ConstStrBuf<8> = ConstStrBuf::from_str("abc");
+		const B: ConstByteBuf<8> = ConstByteBuf::<8>::from_array([0, 1, 2]);
+		const U: ConstStrBuf<8> = ConstStrBuf::from_bytes("тест".as_bytes());
+		assert_eq!(S, "abc");
+		assert_eq!(B, &[0u8, 1, 2][..]);
+		assert_eq!(U, "тест");
+
+		assert!(matches!(
+			ConstStrBuf::<8>::try_from_bytes(&[0xff]),
+			Err(FromBytesError::Utf8(_))
+		));
+		assert!(matches!(
+			ConstStrBuf::<2>::try_from_bytes(b"abc"),
+			Err(FromBytesError::Overflow(_))
+		));
+		assert!(ConstByteBuf::<2>::try_from_bytes(b"abc").is_err());
+
+		const RAW: ConstByteBuf<8> = S.into_bytes_buf();
+		assert_eq!(RAW, &b"abc"[..]);
+		assert_eq!(RAW.try_into_str_buf().unwrap(), "abc");
+		assert!(B.try_into_str_buf().is_ok());
+		assert!(
+			ConstByteBuf::<8>::from_bytes(&[b'a', 0xc3])
+				.try_into_str_buf()
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn mid_buffer_editing() {
+		use cluConstData::buf::ConstByteBuf;
+
+		const fn build() -> ConstStrBuf<32> {
+			let mut buf = ConstStrBuf::<32>::from_str("Hello, {name}!");
+			buf.replace_range(7, 13, "мир");
+			buf.push_front_str(">> ");
+			buf.insert_char(3, '#');
+			buf
+		}
+		const BUILT: ConstStrBuf<32> = build();
+		assert_eq!(BUILT, ">> #Hello, мир!");
+
+		let mut s = BUILT;
+		assert_eq!(s.remove(3), '#');
+		assert_eq!(s.remove(10), 'м');
+		assert_eq!(s, ">> Hello, ир!");
+		s.insert_str(10, "м");
+		assert_eq!(s.drain_range(0, 3), 3);
+		assert_eq!(s, "Hello, мир!");
+		s.truncate(7);
+		assert_eq!(s, "Hello, ");
+		s.truncate(100);
+		assert_eq!(s, "Hello, ");
+		assert!(
+			ConstStrBuf::<8>::from_str("abc")
+				.try_insert_str(1, "123456")
+				.is_err()
+		);
+
+		let mut raw = ConstByteBuf::<8>::from_bytes(&[1, 2, 3]);
+		raw.insert_byte(0, 0);
+		raw.push_front_bytes(&[0xff]);
+		assert_eq!(raw.remove(4), 3);
+		raw.replace_range(1, 2, &[7, 7, 7]);
+		assert_eq!(raw, &[0xff, 7, 7, 7, 1, 2][..]);
+		raw.truncate(1);
+		assert_eq!(raw, &[0xff][..]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn insert_not_char_boundary() {
+		let mut s = ConstStrBuf::<8>::from_str("мир");
+		s.insert_char(1, 'a');
+	}
+
+	#[test]
+	fn utf8_char_access() {
+		const fn build() -> ConstStrBuf<16> {
+			let mut buf = ConstStrBuf::<16>::from_str("ab€д");
+			let _ = buf.pop_char();
+			buf.set_ascii(0, b'A');
+			buf
+		}
+		const BUILT: ConstStrBuf<16> = build();
+		assert_eq!(BUILT, "Ab€");
+
+		let mut s = BUILT;
+		assert_eq!(s.char_count(), 3);
+		assert_eq!(s.last_char(), Some('€'));
+		assert_eq!(s.set_ascii(2, b'x'), None); // old byte is not ASCII
+		assert_eq!(s.set_ascii(1, 0xff), None); // new byte is not ASCII
+		assert_eq!(s.set_ascii(3, b'x'), None); // out of bounds
+		assert_eq!(s.set_ascii(1, b'B'), Some(b'b'));
+
+		s.truncate_chars(5);
+		assert_eq!(s, "AB€");
+		s.truncate_chars(2);
+		assert_eq!(s, "AB");
+		assert_eq!(s.pop_char(), Some('B'));
+		assert_eq!(s.pop_char(), Some('A'));
+		assert_eq!(s.pop_char(), None);
+		assert_eq!(s.last_char(), None);
+		assert_eq!(s.char_count(), 0);
+	}
+
+	#[test]
+	fn element_access() {
+		use cluConstData::buf::ConstByteBuf;
+
+		let mut raw = ConstByteBuf::<8>::new();
+		assert_eq!(raw.get(0), None);
+		assert_eq!(raw.get_mut(0), None);
+		assert_eq!(raw.first(), None);
+		assert_eq!(raw.last(), None);
+		assert_eq!(raw.get_range(0, 0), Some(&[][..]));
+		assert_eq!(raw.get_range(0, 1), None);
+		assert_eq!(raw.pop(), None);
+
+		raw.write_bytes(&[1, 2, 3]);
+		assert_eq!(raw.get(2), Some(&3));
+		assert_eq!(raw.get(3), None); // one past the last written byte
+		assert_eq!(raw.get_mut(3), None);
+		*raw.get_mut(0).unwrap() = 10;
+		assert_eq!(raw.first(), Some(&10));
+		assert_eq!(raw.last(), Some(&3));
+		assert_eq!(raw.get_range(1, 3), Some(&[2, 3][..]));
+		assert_eq!(raw.get_range(2, 1), None);
+		assert_eq!(raw.get_range(1, 4), None);
+
+		assert_eq!(raw.pop(), Some(3));
+		assert_eq!(raw.pop(), Some(2));
+		assert_eq!(raw.get(1), None);
+		assert_eq!(raw.pop(), Some(10));
+		assert_eq!(raw.pop(), None);
+
+		let mut s = ConstStrBuf::<8>::from_str("ab");
+		assert_eq!(s.get(1), Some(&b'b'));
+		assert_eq!(s.get(2), None);
+		assert_eq!(unsafe { s.get_mut(2) }, None);
+		assert_eq!(s.first(), Some(&b'a'));
+		assert_eq!(s.last(), Some(&b'b'));
+		assert_eq!(s.get_range(0, 2), Some(&b"ab"[..]));
+		assert_eq!(unsafe { s.pop() }, Some(b'b'));
+		assert_eq!(s, "a");
+	}
+
+	#[test]
+	fn into_array() {
+		use cluConstData::buf::ConstByteBuf;
+
+		const ARRAY: (usize, [u8; 4]) =
+			ConstStrBuf::<4>::from_str("ab").into_array_filled_with_space();
+		assert_eq!(ARRAY, (2, *b"ab  "));
+
+		let (len, array) = ConstByteBuf::<4>::from_bytes(&[1]).into_array(0xff);
+		assert_eq!((len, array), (1, [1, 0xff, 0xff, 0xff]));
+
+		let zero = ConstStrBuf::<4>::from_str("ab").into_array_filled_with_zero();
+		assert_eq!(zero, (2, *b"ab\0\0"));
+	}
+
+	#[test]
+	fn into_array_terminated() {
+		use cluConstData::buf::{ConstByteBuf, IntoArrayError, LenPrefix};
+
+		const NUL: (usize, [u8; 4]) = ConstStrBuf::<4>::from_str("abc").into_array_nul_terminated();
+		assert_eq!(NUL, (3, *b"abc\0"));
+		assert_eq!(
+			ConstStrBuf::<4>::from_str("abcd").try_into_array_nul_terminated(),
+			Err(IntoArrayError::Overflow)
+		);
+		assert_eq!(
+			ConstByteBuf::<4>::from_bytes(b"a\0b").try_into_array_nul_terminated(),
+			Err(IntoArrayError::InteriorNul(1))
+		);
+
+		const PASCAL: (usize, [u8; 5]) =
+			ConstStrBuf::<3>::from_str("abc").into_array_with_len_prefix(LenPrefix::U8);
+		assert_eq!(PASCAL, (4, *b"\x03abc\0"));
+
+		let buf = ConstByteBuf::<2>::from_bytes(&[7, 8]);
+		assert_eq!(
+			buf.into_array_with_len_prefix::<6>(LenPrefix::U32Be),
+			(6, [0, 0, 0, 2, 7, 8])
+		);
+		assert_eq!(
+			buf.into_array_with_len_prefix::<4>(LenPrefix::U16Le),
+			(4, [2, 0, 7, 8])
+		);
+		assert_eq!(
+			buf.try_into_array_with_len_prefix::<3>(LenPrefix::U16Be),
+			Err(IntoArrayError::Overflow)
+		);
+		assert_eq!(
+			ConstByteBuf::<300>::from_array([1; 300])
+				.try_into_array_with_len_prefix::<512>(LenPrefix::U8),
+			Err(IntoArrayError::LenTooLarge)
+		);
+	}
+
+	#[test]
+	fn nul_terminated_buf() {
+		use cluConstData::buf::ConstCStrBuf;
+
+		const fn build() -> ConstCStrBuf<8> {
+			let mut buf = ConstCStrBuf::<8>::new();
+			buf.push_str("id=");
+			buf.push_usize(42);
+			buf
+		}
+		const BUILT: ConstCStrBuf<8> = build();
+		assert_eq!(BUILT.as_cstr(), c"id=42");
+		assert_eq!(BUILT.as_bytes_with_nul(), b"id=42\0");
+		assert_eq!(ConstCStrBuf::<8>::new().as_cstr(), c"");
+
+		let mut buf = BUILT;
+		assert_eq!(buf.capacity(), 7);
+		assert_eq!(buf.available(), 2);
+		assert!(buf.try_push_str("abc").is_err());
+		buf.push_str("ab");
+		assert_eq!(buf.as_cstr(), c"id=42ab");
+
+		buf.replace_range(0, 3, b"");
+		assert_eq!(buf.as_cstr(), c"42ab");
+		buf.truncate(1);
+		assert_eq!(buf.as_cstr(), c"4");
+		assert_eq!(buf.pop(), Some(b'4'));
+		assert_eq!(buf.as_cstr(), c"");
+
+		buf.write_bytes(b"a\0b");
+		assert!(buf.try_as_cstr().is_err());
+		buf.clear();
+		assert_eq!(buf.as_cstr(), c"");
+
+		let mut buf: ConstCStrBuf<8> = [b"ab".as_slice(), b"d"].into_iter().collect();
+		buf.insert_byte(2, b'c');
+		buf.push_front_bytes(b"<");
+		buf.extend(b">");
+		assert_eq!(buf.as_cstr(), c"<abcd>");
+		assert_eq!(buf.remove(0), b'<');
+		buf.insert_str(0, "[");
+		assert!(buf.try_insert_bytes(1, b"xy").is_err());
+		assert_eq!(buf.as_bytes_with_nul(), b"[abcd>\0");
+	}
 }
@@ -0,0 +1,92 @@
+#[cfg(all(feature = "const_buf", feature = "const_data"))]
+mod test_build_info {
+	use cluConstData::build_info::BuildInfo;
+
+	cluConstData::build_info! {
+		mod build;
+	}
+
+	#[test]
+	fn build_info_consts() {
+		assert_eq!(build::PKG_NAME, env!("CARGO_PKG_NAME"));
+		assert_eq!(build::PKG_VERSION, env!("CARGO_PKG_VERSION"));
+		assert_eq!(
+			build::PROFILE,
+			if cfg!(debug_assertions) {
+				"debug"
+			} else {
+				"release"
+			}
+		);
+		assert!(build::CFG_TARGET.starts_with(cluConstData::build_info::TARGET_ARCH));
+
+		let epoch = build::SOURCE_DATE_EPOCH
+			.map(|a| format!(", epoch {a}"))
+			.unwrap_or_default();
+		assert_eq!(
+			build::BANNER,
+			format!(
+				"{} {} ({}, {}{})",
+				build::PKG_NAME,
+				build::PKG_VERSION,
+				build::CFG_TARGET,
+				build::PROFILE,
+				epoch
+			)
+		);
+	}
+
+	#[test]
+	fn build_info_record() {
+		assert_eq!(build::RECORD.len(), build::RECORD_LEN);
+		assert_eq!(
+			BuildInfo::from_record(build::RECORD.as_bytes()),
+			Ok(build::INFO)
+		);
+
+		const INFO: BuildInfo = BuildInfo {
+			name: "fw",
+			version: "1.0.0",
+			cfg_target: "arm-unknown-none",
+			debug: false,
+			source_date_epoch: Some(1_700_000_000),
+		};
+		const RECORD: cluConstData::buf::ConstByteBuf<{ INFO.record_len() }> = INFO.to_record();
+		let bytes = RECORD.as_bytes();
+		assert_eq!(&bytes[..4], b"\x02\x00fw");
+		assert_eq!(bytes[bytes.len() - 10..][..2], [0, 1]);
+		assert_eq!(BuildInfo::from_record(bytes), Ok(INFO));
+		assert!(BuildInfo::from_record(&bytes[..bytes.len() - 1]).is_err());
+
+		const BANNER: cluConstData::buf::ConstStrBuf<{ INFO.banner_len() }> = INFO.to_banner();
+		assert_eq!(
+			BANNER,
+			"fw 1.0.0 (arm-unknown-none, release, epoch 1700000000)"
+		);
+	}
+
+	#[test]
+	fn source_date_epoch() {
+		use cluConstData::build_info::parse_source_date_epoch;
+		use cluConstData::parse::ParseIntError;
+
+		assert_eq!(parse_source_date_epoch("1700000000"), Ok(1_700_000_000));
+		assert_eq!(parse_source_date_epoch("0"), Ok(0));
+		assert_eq!(
+			parse_source_date_epoch("18446744073709551615"),
+			Ok(u64::MAX)
+		);
+		assert_eq!(parse_source_date_epoch(""), Err(ParseIntError::Empty));
+		assert_eq!(
+			parse_source_date_epoch("18446744073709551616"),
+			Err(ParseIntError::PosOverflow)
+		);
+		for s in ["0x6553f100", "1_700_000_000", "+1", "-1", " 1", "1.0"] {
+			assert_eq!(
+				parse_source_date_epoch(s),
+				Err(ParseIntError::InvalidDigit),
+				"{s}"
+			);
+		}
+	}
+}
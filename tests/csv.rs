@@ -0,0 +1,74 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_csv {
+	use cluConstData::buf::size::csv_row_max_len;
+	use cluConstData::buf::{ConstCsvWriter, ConstStrBuf, CsvLineEnding};
+	use cluConstData::{const_data, csv_row};
+
+	#[test]
+	fn quoting() {
+		let mut csv = ConstCsvWriter::<128>::new();
+		csv.field("plain")
+			.field("")
+			.field("a,b")
+			.field("say \"hi\"");
+		csv.field("\"").field("multi\nline").field("cr\r").end_row();
+		csv.field_u64(u64::MAX)
+			.field_i64(i64::MIN)
+			.field_i64(0)
+			.end_row();
+
+		assert_eq!(
+			csv.as_str(),
+			"plain,,\"a,b\",\"say \"\"hi\"\"\",\"\"\"\",\"multi\nline\",\"cr\r\"\r\n\
+			18446744073709551615,-9223372036854775808,0\r\n"
+		);
+	}
+
+	#[test]
+	fn custom_format() {
+		let mut csv = ConstCsvWriter::<32>::with_format(b';', CsvLineEnding::Lf);
+		csv.field("a,b").field("c;d").end_row();
+		csv.end_row();
+		assert_eq!(csv.into_inner(), "a,b;\"c;d\"\n\n");
+	}
+
+	#[test]
+	#[should_panic]
+	fn invalid_separator() {
+		let _ = ConstCsvWriter::<8>::with_format(b'"', CsvLineEnding::Lf);
+	}
+
+	#[test]
+	fn overflow() {
+		let mut csv = ConstCsvWriter::<8>::new();
+		csv.field("abc");
+		assert!(csv.try_field("a\"b").is_err());
+		assert!(csv.try_field_i64(-1000).is_err());
+		assert!(csv.try_row(&["x", "y"]).is_err());
+		assert_eq!(csv.as_str(), "abc");
+
+		assert!(csv.try_field_i64(-100).is_ok());
+		assert!(csv.try_end_row().is_err());
+		assert_eq!(csv.as_str(), "abc,-100");
+	}
+
+	#[test]
+	fn header_row() {
+		const COLUMNS: &[&str] = &["id", "name \"full\"", "a,b"];
+		const HEADER: ConstStrBuf<{ csv_row_max_len(COLUMNS) }> = {
+			let mut csv = ConstCsvWriter::new();
+			csv.row(COLUMNS);
+			csv.into_inner()
+		};
+		const_data! {
+			const LOG: &str = csv_row!(COLUMNS), "1,x,y\r\n";
+			const TSV: &str = csv_row!(COLUMNS, b'\t', Lf);
+		}
+
+		assert_eq!(HEADER, "id,\"name \"\"full\"\"\",\"a,b\"\r\n");
+		assert_eq!(csv_row!(COLUMNS), HEADER.as_str());
+		assert_eq!(LOG, "id,\"name \"\"full\"\"\",\"a,b\"\r\n1,x,y\r\n");
+		assert_eq!(TSV, "id\t\"name \"\"full\"\"\"\ta,b\n");
+		assert_eq!(csv_row!(&[]), "\r\n");
+	}
+}
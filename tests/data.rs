@@ -77,4 +77,38 @@ mod test_const_ata {
 
 		assert_eq!(<(A, B)>::as_str(), "A + B");
 	}
+
+	#[test]
+	fn non_zeroable_const_data() {
+		use core::num::NonZeroU8;
+
+		const ONE: NonZeroU8 = NonZeroU8::new(1).unwrap();
+		const TWO: NonZeroU8 = NonZeroU8::new(2).unwrap();
+
+		const_data! {
+			const CHARS: [char; 3] = &['a'], &['b', 'c'];
+			const NON_ZERO: &[NonZeroU8] = &[ONE], &[TWO];
+			const STRS: &[&str] = &["Accept"], &["Content-Type"];
+		}
+
+		assert_eq!(CHARS, ['a', 'b', 'c']);
+		assert_eq!(NON_ZERO, &[ONE, TWO]);
+		assert_eq!(STRS, &["Accept", "Content-Type"]);
+	}
+
+	#[test]
+	fn cstr_const_data() {
+		use core::ffi::CStr;
+
+		const_data! {
+			const NAME: &str = "app";
+			const PATH: &CStr = "/etc/", NAME, ".conf";
+			pub const VERSION: &'static CStr = "v", "1";
+			const SINGLE: &CStr = c"single";
+		}
+
+		assert_eq!(PATH, c"/etc/app.conf");
+		assert_eq!(VERSION, c"v1");
+		assert_eq!(SINGLE, c"single");
+	}
 }
@@ -0,0 +1,90 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_escape {
+	use cluConstData::buf::size::{debug_bytes_max_len, escaped_max_len};
+	use cluConstData::buf::{ConstStrBuf, Escape};
+	use cluConstData::{debug_bytes_str, escape_str, quoted_str};
+
+	const SAMPLE: &str = "q\"\\\n\r\t\0\x01\x07\x08\x0b\x0c\x7f\u{80}ü<&>'";
+
+	fn escaped(s: &str, escape: Escape) -> ConstStrBuf<256> {
+		let mut buf = ConstStrBuf::new();
+		buf.push_escaped(s, escape);
+		buf
+	}
+
+	#[test]
+	fn debug_matches_core() {
+		let mut buf = ConstStrBuf::<256>::new();
+		buf.push_quoted(SAMPLE, Escape::Debug);
+		assert_eq!(buf.as_str(), format!("{SAMPLE:?}"));
+
+		for c in ('\0'..='\u{9f}').chain(['ü', '€', '😀']) {
+			let s = c.encode_utf8(&mut [0; 4]).to_owned();
+			let mut buf = ConstStrBuf::<16>::new();
+			buf.push_quoted(&s, Escape::Debug);
+			if c != '\'' {
+				assert_eq!(buf.as_str(), format!("{s:?}"));
+			}
+		}
+	}
+
+	#[test]
+	fn modes() {
+		assert_eq!(
+			escaped(SAMPLE, Escape::Json).as_str(),
+			r#"q\"\\\n\r\t\u0000\u0001\u0007\b\u000b\f"#.to_owned() + "\x7f\u{80}ü<&>'"
+		);
+		assert_eq!(
+			escaped(SAMPLE, Escape::C).as_str(),
+			r#"q\"\\\n\r\t\000\001\a\b\v\f\177"#.to_owned() + "\u{80}ü<&>'"
+		);
+		assert_eq!(
+			escaped(SAMPLE, Escape::Html).as_str(),
+			"q\"\\\n\r\t\0\x01\x07\x08\x0b\x0c\x7f\u{80}ü".replace('"', "&quot;")
+				+ "&lt;&amp;&gt;&#39;"
+		);
+	}
+
+	#[test]
+	fn max_len() {
+		for escape in [Escape::Debug, Escape::Json, Escape::C, Escape::Html] {
+			for c in '\0'..='\u{ff}' {
+				let s = c.encode_utf8(&mut [0; 4]).to_owned();
+				assert!(escaped(&s, escape).len() <= escaped_max_len(s.len(), escape));
+			}
+		}
+
+		let mut small = ConstStrBuf::<4>::new();
+		assert!(small.try_push_escaped("a\nb", Escape::Json).is_ok());
+		assert!(small.try_push_escaped("\n", Escape::Json).is_err());
+		assert!(small.try_push_quoted("", Escape::Json).is_err());
+		assert_eq!(small, r"a\nb");
+	}
+
+	#[test]
+	fn debug_bytes() {
+		let data: Vec<u8> = (0..=255).collect();
+		let mut buf = ConstStrBuf::<1024>::new();
+		buf.push_debug_bytes(&data);
+		assert_eq!(buf.as_str(), format!("b\"{}\"", data.escape_ascii()));
+		assert!(buf.len() <= debug_bytes_max_len(data.len()));
+
+		let mut small = ConstStrBuf::<6>::new();
+		assert!(small.try_push_debug_bytes(b"\0").is_err());
+		assert_eq!(small, "");
+	}
+
+	#[test]
+	fn macros() {
+		const MESSAGE: &str = "file \"a.txt\"\n";
+		const DEBUG: &str = escape_str!(MESSAGE);
+		const JSON: &str = quoted_str!(MESSAGE, Json);
+		const HTML: &str = escape_str!("<a href='x'>", Html);
+		const BYTES: &str = debug_bytes_str!(b"\x00ok\n");
+
+		assert_eq!(DEBUG, r#"file \"a.txt\"\n"#);
+		assert_eq!(JSON, r#""file \"a.txt\"\n""#);
+		assert_eq!(HTML, "&lt;a href=&#39;x&#39;&gt;");
+		assert_eq!(BYTES, r#"b"\x00ok\n""#);
+	}
+}
@@ -0,0 +1,48 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_hex {
+	use cluConstData::buf::size::hex_encoded_len;
+	use cluConstData::buf::{ConstStrBuf, HexCase};
+	use cluConstData::{concat_bytes, hex_str};
+
+	#[test]
+	fn push_hex_bytes() {
+		let mut buf = ConstStrBuf::<32>::new();
+		assert_eq!(
+			buf.push_hex_bytes(&[0x00, 0x0f, 0xa0, 0xff], HexCase::Lower, ""),
+			8
+		);
+		assert_eq!(buf, "000fa0ff");
+
+		buf.clear();
+		assert_eq!(
+			buf.push_hex_bytes(&[0xde, 0xad, 0xbe, 0xef], HexCase::Upper, ":"),
+			11
+		);
+		assert_eq!(buf, "DE:AD:BE:EF");
+
+		buf.clear();
+		buf.push_hex_bytes(&[1, 2], HexCase::Lower, " · ");
+		assert_eq!(buf, "01 · 02");
+		assert_eq!(buf.len(), hex_encoded_len(2, " · "));
+
+		buf.clear();
+		assert_eq!(buf.push_hex_bytes(&[], HexCase::Lower, ":"), 0);
+		assert!(
+			buf.try_push_hex_bytes(&[0; 16], HexCase::Lower, ":")
+				.is_err()
+		);
+		assert!(buf.is_empty());
+	}
+
+	#[test]
+	fn hex_str_macro() {
+		const BLOB: &[u8] = concat_bytes!(&[0xde, 0xad], &[0xbe, 0xef]);
+		const KEY: [u8; 3] = [0x01, 0xab, 0xff];
+		const EMPTY: &[u8] = &[];
+
+		assert_eq!(hex_str!(BLOB), "deadbeef");
+		assert_eq!(hex_str!(BLOB, Upper), "DEADBEEF");
+		assert_eq!(hex_str!(KEY, Lower, ":"), "01:ab:ff");
+		assert_eq!(hex_str!(EMPTY), "");
+	}
+}
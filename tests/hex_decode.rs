@@ -0,0 +1,48 @@
+use cluConstData::hex::{HexDecodeError, decode_hex, decoded_len};
+use cluConstData::{concat_array, concat_bytes, hex};
+
+#[test]
+fn decode() {
+	assert_eq!(decode_hex::<4>("DEADbeef"), Ok([0xde, 0xad, 0xbe, 0xef]));
+	assert_eq!(decode_hex::<3>(" 01:02\t03\n"), Ok([1, 2, 3]));
+	assert_eq!(decode_hex::<0>(""), Ok([]));
+	assert_eq!(decoded_len("00 11 22"), Ok(3));
+
+	assert_eq!(decoded_len("0"), Err(HexDecodeError::IncompleteByte));
+	assert_eq!(decoded_len("0 0"), Err(HexDecodeError::IncompleteByte));
+	assert_eq!(decoded_len("0x00"), Err(HexDecodeError::InvalidChar));
+	assert_eq!(decoded_len("00-11"), Err(HexDecodeError::InvalidChar));
+	assert_eq!(decode_hex::<1>("0011"), Err(HexDecodeError::LengthMismatch));
+	assert_eq!(decode_hex::<3>("0011"), Err(HexDecodeError::LengthMismatch));
+}
+
+#[test]
+fn hex_macro() {
+	const MAGIC: [u8; 4] = hex!("de ad be ef");
+	const FRAME: &[u8] = concat_bytes!(&MAGIC, &hex!("0a:0b"));
+	const WORDS: [u8; 6] = concat_array!(:[u8] = &hex!("01 02"), &hex!("03 04 05 06"));
+
+	assert_eq!(MAGIC, [0xde, 0xad, 0xbe, 0xef]);
+	assert_eq!(FRAME, &[0xde, 0xad, 0xbe, 0xef, 0x0a, 0x0b]);
+	assert_eq!(WORDS, [1, 2, 3, 4, 5, 6]);
+	assert_eq!(hex!(""), []);
+}
+
+#[cfg(any(test, feature = "const_data"))]
+mod test_hex_const_data {
+	use cluConstData::{const_data, hex};
+
+	#[test]
+	fn hex_const_data() {
+		const_data! {
+			const VECTOR: [u8; 8] = &hex!("00112233"), &hex!("44556677");
+			const KEY: &[u8] = &hex!("
+				00 01 02 03
+				04 05 06 07
+			"), &[0xff];
+		}
+
+		assert_eq!(VECTOR, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]);
+		assert_eq!(KEY, &[0, 1, 2, 3, 4, 5, 6, 7, 0xff]);
+	}
+}
@@ -0,0 +1,96 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_json {
+	use cluConstData::buf::{ConstJsonWriter, ConstStrBuf, JsonError};
+
+	#[test]
+	fn document() {
+		let mut json = ConstJsonWriter::<128>::new();
+		json.begin_object();
+		json.key("name").value_str("line\n\"quoted\"");
+		json.key("empty_object").begin_object().end();
+		json.key("empty_array").begin_array().end();
+		json.key("nested").begin_array();
+		json.begin_array().value_u64(0).value_u64(u64::MAX).end();
+		json.begin_object().key("k").null().end();
+		json.value_bool(false);
+		json.end();
+		json.end();
+
+		assert_eq!(
+			json.finish().unwrap(),
+			r#"{"name":"line\n\"quoted\"","empty_object":{},"empty_array":[],"nested":[[0,18446744073709551615],{"k":null},false]}"#
+		);
+	}
+
+	#[test]
+	fn scalar_root() {
+		let mut json = ConstJsonWriter::<8>::new();
+		json.value_str("ok");
+		assert_eq!(json.finish().unwrap(), r#""ok""#);
+	}
+
+	#[test]
+	fn misuse() {
+		let check = |f: fn(&mut ConstJsonWriter<64>)| {
+			let mut json = ConstJsonWriter::new();
+			f(&mut json);
+			json.finish()
+		};
+
+		use JsonError::*;
+		assert_eq!(check(|_| {}), Err(Incomplete));
+		assert_eq!(check(|j| _ = j.begin_object()), Err(Incomplete));
+		assert_eq!(check(|j| _ = j.key("a")), Err(UnexpectedKey));
+		assert_eq!(check(|j| _ = j.begin_array().key("a")), Err(UnexpectedKey));
+		assert_eq!(
+			check(|j| _ = j.begin_object().key("a").key("b")),
+			Err(UnexpectedKey)
+		);
+		assert_eq!(
+			check(|j| _ = j.begin_object().value_u64(1)),
+			Err(ExpectedKey)
+		);
+		assert_eq!(
+			check(|j| _ = j.begin_object().key("a").end()),
+			Err(MissingValue)
+		);
+		assert_eq!(check(|j| _ = j.null().end()), Err(UnbalancedEnd));
+		assert_eq!(check(|j| _ = j.null().null()), Err(MultipleRoots));
+		assert_eq!(
+			check(|j| {
+				for _ in 0..65 {
+					j.begin_array();
+				}
+			}),
+			Err(TooDeep)
+		);
+
+		// the first error is kept
+		let mut json = ConstJsonWriter::<64>::new();
+		json.end().begin_object().key("a");
+		assert_eq!(json.error(), Some(UnbalancedEnd));
+		assert_eq!(json.as_str(), "");
+	}
+
+	#[test]
+	fn overflow() {
+		let mut json = ConstJsonWriter::<8>::new();
+		json.begin_array().value_str("long string").end();
+		assert_eq!(json.finish(), Err(JsonError::Overflow));
+	}
+
+	#[test]
+	fn const_document() {
+		const STATUS: ConstStrBuf<32> = {
+			let mut json = ConstJsonWriter::new();
+			json.begin_object().key("status").value_str("ok").end();
+
+			match json.finish() {
+				Ok(a) => a,
+				Err(e) => panic!("{}", e.as_str()),
+			}
+		};
+
+		assert_eq!(STATUS, r#"{"status":"ok"}"#);
+	}
+}
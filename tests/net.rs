@@ -0,0 +1,182 @@
+use cluConstData::net::*;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+const IPV6_SAMPLES: &[&str] = &[
+	"::",
+	"::1",
+	"1::",
+	"2001:db8::1",
+	"2001:db8:0:0:1:0:0:1",
+	"2001:0:0:1:0:0:0:1",
+	"2001:db8:0:1:1:1:1:1",
+	"fe80::1:2:3:4",
+	"1:2:3:4:5:6:7:8",
+	"1:2:3:4:5:6:7::",
+	"::ffff:10.0.0.1",
+	"::10.0.0.1",
+	"64:ff9b::192.0.2.33",
+	"1:2:3:4:5:6:1.2.3.4",
+	"FFFF:ffff:FfFf:ffff:ffff:ffff:ffff:ffff",
+];
+
+#[test]
+fn parse_addrs() {
+	const GATEWAY: Ipv4Addr = cluConstData::parse_ipv4!("10.0.0.1");
+	const ENDPOINT: SocketAddr = cluConstData::parse_socket_addr!("[fe80::1%3]:8080");
+	assert_eq!(GATEWAY, Ipv4Addr::new(10, 0, 0, 1));
+	assert_eq!(ENDPOINT, "[fe80::1%3]:8080".parse().unwrap());
+
+	for s in ["0.0.0.0", "255.255.255.255", "192.168.1.10"] {
+		assert_eq!(parse_ipv4(s), Ok(s.parse().unwrap()), "{s}");
+	}
+	for s in [
+		"",
+		"1.2.3",
+		"1.2.3.4.5",
+		"256.0.0.1",
+		"01.2.3.4",
+		"1.2.3.4 ",
+		"1..2.3",
+	] {
+		assert_eq!(parse_ipv4(s), Err(AddrParseError::Ipv4), "{s}");
+		assert!(s.parse::<Ipv4Addr>().is_err(), "{s}");
+	}
+
+	for s in IPV6_SAMPLES {
+		assert_eq!(parse_ipv6(s), Ok(s.parse().unwrap()), "{s}");
+	}
+	for s in [
+		"",
+		":",
+		":::",
+		"1:::2",
+		"1::2::3",
+		"12345::",
+		"1:2:3:4:5:6:7:8:9",
+		"1:2:3:4:5:6:7",
+		":1::",
+		"::1.2.3",
+		"1:2:3:4:5:6:7:1.2.3.4",
+		"1.2.3.4::",
+		"1.2.3.4::1",
+		"1:2:3:4:5:1.2.3.4::",
+		"::1.2.3.4:5",
+		"1.2.3.4",
+		"g::",
+	] {
+		assert_eq!(parse_ipv6(s), Err(AddrParseError::Ipv6), "{s}");
+		assert!(s.parse::<Ipv6Addr>().is_err(), "{s}");
+	}
+
+	assert_eq!(parse_ip("::1"), Ok(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+	assert_eq!(parse_ip("127.0.0.1"), Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+
+	for s in ["10.0.0.1:80", "[::1]:0", "[2001:db8::1%4294967295]:65535"] {
+		assert_eq!(parse_socket_addr(s), Ok(s.parse().unwrap()), "{s}");
+	}
+	for s in [
+		"10.0.0.1",
+		"10.0.0.1:",
+		"10.0.0.1:65536",
+		"::1:80",
+		"[::1]80",
+		"[::1%]:80",
+		"[1.2.3.4::]:80",
+	] {
+		assert_eq!(parse_socket_addr(s), Err(AddrParseError::SocketAddr), "{s}");
+	}
+
+	// leading zeros are accepted in ports and scope ids, as in `core`
+	for s in [
+		"10.0.0.1:080",
+		"10.0.0.1:00000000065535",
+		"[::1%007]:0",
+		"[fe80::1%0004294967295]:00",
+		"10.0.0.1:065536",
+		"[::1%04294967296]:80",
+		"010.0.0.1:80",
+	] {
+		assert_eq!(
+			parse_socket_addr(s).ok(),
+			s.parse::<SocketAddr>().ok(),
+			"{s}"
+		);
+	}
+
+	assert_eq!(
+		parse_mac("00:1a:2B:3c:4d:5E"),
+		Ok([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])
+	);
+	assert_eq!(
+		parse_mac("00-1a-2b-3c-4d-5e"),
+		Ok([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])
+	);
+	assert_eq!(parse_mac("00:1a-2b:3c:4d:5e"), Err(AddrParseError::Mac));
+	assert_eq!(parse_mac("00:1a:2b:3c:4d"), Err(AddrParseError::Mac));
+	assert_eq!(parse_mac("0g:1a:2b:3c:4d:5e"), Err(AddrParseError::Mac));
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_net {
+	use super::IPV6_SAMPLES;
+	use cluConstData::buf::size::ConstByteBufDisplaySize;
+	use cluConstData::buf::{ConstByteBuf, ConstStrBuf, MacStyle};
+	use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+	#[test]
+	fn push_addrs() {
+		const ADDR: ConstStrBuf<{ SocketAddr::MAX_DISPLAY_LEN }> = {
+			let mut buf = ConstStrBuf::new();
+			buf.push_socket_addr(cluConstData::parse_socket_addr!("[2001:db8::1]:443"));
+			buf
+		};
+		assert_eq!(ADDR, "[2001:db8::1]:443");
+
+		let mut samples: Vec<Ipv6Addr> = IPV6_SAMPLES.iter().map(|s| s.parse().unwrap()).collect();
+		samples.push(Ipv6Addr::new(0, 0, 1, 0, 0, 1, 0, 0));
+		samples.push(Ipv6Addr::new(1, 0, 1, 0, 1, 0, 1, 0));
+		samples.push(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0, 0));
+		for addr in samples {
+			let mut buf = ConstStrBuf::<{ Ipv6Addr::MAX_DISPLAY_LEN }>::new();
+			buf.push_ipv6(addr);
+			assert_eq!(buf.as_str(), addr.to_string());
+		}
+
+		for addr in [
+			Ipv4Addr::UNSPECIFIED,
+			Ipv4Addr::BROADCAST,
+			Ipv4Addr::new(10, 0, 200, 1),
+		] {
+			let mut buf = ConstStrBuf::<{ Ipv4Addr::MAX_DISPLAY_LEN }>::new();
+			buf.push_ipv4(addr);
+			assert_eq!(buf.as_str(), addr.to_string());
+		}
+
+		for s in [
+			"10.0.0.1:80",
+			"[::1]:0",
+			"[::ffff:1.2.3.4]:1",
+			"[ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff%4294967295]:65535",
+		] {
+			let addr: SocketAddr = s.parse().unwrap();
+			let mut buf = ConstStrBuf::<{ SocketAddr::MAX_DISPLAY_LEN }>::new();
+			buf.push_socket_addr(addr);
+			assert_eq!(buf.as_str(), addr.to_string());
+		}
+
+		let mut small = ConstByteBuf::<8>::new();
+		assert!(small.try_push_ipv4(Ipv4Addr::BROADCAST).is_err());
+		assert!(small.is_empty());
+	}
+
+	#[test]
+	fn push_mac() {
+		const MAC: [u8; 6] = cluConstData::parse_mac!("00:1A:2b:3c:4d:5e");
+
+		let mut buf = ConstStrBuf::<{ MacStyle::LEN * 2 }>::new();
+		buf.push_mac(&MAC, MacStyle::Colon);
+		buf.push_mac(&MAC, MacStyle::Dash);
+		assert_eq!(buf, "00:1a:2b:3c:4d:5e00-1a-2b-3c-4d-5e");
+		assert!(buf.try_push_mac(&MAC, MacStyle::Colon).is_err());
+	}
+}
@@ -0,0 +1,69 @@
+use cluConstData::parse::*;
+
+#[test]
+fn parse_unsigned() {
+	const SIZE: usize = match parse_usize("4_096") {
+		Ok(a) => a,
+		Err(e) => panic!("{}", e.as_str()),
+	};
+	assert_eq!(SIZE, 4096);
+
+	assert_eq!(parse_u8("255"), Ok(255));
+	assert_eq!(parse_u8("+7"), Ok(7));
+	assert_eq!(parse_u8("256"), Err(ParseIntError::PosOverflow));
+	assert_eq!(parse_u32("0xFF_FF"), Ok(0xFFFF));
+	assert_eq!(parse_u32("0o17"), Ok(0o17));
+	assert_eq!(parse_u32("0b1010"), Ok(10));
+	assert_eq!(parse_u64(&u64::MAX.to_string()), Ok(u64::MAX));
+	assert_eq!(parse_u128(&u128::MAX.to_string()), Ok(u128::MAX));
+	assert_eq!(parse_u32("-0"), Ok(0));
+	assert_eq!(parse_u32("-1"), Err(ParseIntError::NegOverflow));
+
+	assert_eq!(parse_u32(""), Err(ParseIntError::Empty));
+	assert_eq!(parse_u32("0x"), Err(ParseIntError::Empty));
+	assert_eq!(parse_u32("_1"), Err(ParseIntError::InvalidDigit));
+	assert_eq!(parse_u32("12a"), Err(ParseIntError::InvalidDigit));
+	assert_eq!(parse_u32(" 1"), Err(ParseIntError::InvalidDigit));
+
+	assert_eq!(parse_u16_radix("ff", 16), Ok(255));
+	assert_eq!(parse_u16_radix("zz", 36), Ok(35 * 36 + 35));
+	assert_eq!(
+		parse_u16_radix("0xff", 16),
+		Err(ParseIntError::InvalidDigit)
+	);
+	assert_eq!(parse_u16_radix("1", 37), Err(ParseIntError::InvalidRadix));
+}
+
+#[test]
+fn parse_signed() {
+	assert_eq!(parse_i8("-128"), Ok(i8::MIN));
+	assert_eq!(parse_i8("127"), Ok(i8::MAX));
+	assert_eq!(parse_i8("128"), Err(ParseIntError::PosOverflow));
+	assert_eq!(parse_i8("-129"), Err(ParseIntError::NegOverflow));
+	assert_eq!(parse_i64("-0x10"), Ok(-16));
+	assert_eq!(parse_i64(&i64::MIN.to_string()), Ok(i64::MIN));
+	assert_eq!(parse_i128(&i128::MIN.to_string()), Ok(i128::MIN));
+	assert_eq!(parse_i128(&i128::MAX.to_string()), Ok(i128::MAX));
+	assert_eq!(parse_isize_radix("-1_0", 2), Ok(-2));
+	assert_eq!(parse_i32("-"), Err(ParseIntError::Empty));
+}
+
+#[test]
+fn env_int() {
+	use cluConstData::{env_i64, env_int, env_u32, env_usize};
+
+	const MAJOR: usize = env_usize!("CARGO_PKG_VERSION_MAJOR");
+	const MINOR: u32 = env_u32!("CARGO_PKG_VERSION_MINOR");
+	const DEFAULT: usize = env_usize!("CLUCONSTDATA_TEST_NOT_SET", default = 64);
+	const NEG_DEFAULT: i64 = env_i64!("CLUCONSTDATA_TEST_NOT_SET", default = -1);
+	const PATCH: u8 = env_int!(u8, "CARGO_PKG_VERSION_PATCH", default = 0);
+
+	assert_eq!(MAJOR.to_string(), env!("CARGO_PKG_VERSION_MAJOR"));
+	assert_eq!(MINOR.to_string(), env!("CARGO_PKG_VERSION_MINOR"));
+	assert_eq!(PATCH.to_string(), env!("CARGO_PKG_VERSION_PATCH"));
+	assert_eq!(DEFAULT, 64);
+	assert_eq!(NEG_DEFAULT, -1);
+
+	static BUF: [u8; DEFAULT] = [0; DEFAULT];
+	assert_eq!(BUF.len(), 64);
+}
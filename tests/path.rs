@@ -0,0 +1,62 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_path {
+	use cluConstData::buf::{ConstStrBuf, PathError};
+	use cluConstData::concat_path;
+
+	#[test]
+	fn push_path_segment() {
+		let join = |segments: &[&str]| {
+			let mut buf = ConstStrBuf::<64>::new();
+			for segment in segments {
+				buf.try_push_path_segment(segment)?;
+			}
+
+			Ok::<_, PathError>(buf)
+		};
+
+		assert_eq!(join(&["/"]).unwrap(), "/");
+		assert_eq!(join(&["/etc", "app"]).unwrap(), "/etc/app");
+		assert_eq!(join(&["//etc//", "//app//"]).unwrap(), "/etc/app");
+		assert_eq!(join(&["a", "./b/.", "c"]).unwrap(), "a/b/c");
+		assert_eq!(join(&["/a/b", "../../c"]).unwrap(), "/c");
+		assert_eq!(join(&["/a", ".."]).unwrap(), "/");
+		assert_eq!(join(&["a", ".."]).unwrap(), "");
+		assert_eq!(join(&["", "a"]).unwrap(), "a");
+		assert_eq!(join(&["/ü", "ß"]).unwrap(), "/ü/ß");
+
+		assert_eq!(join(&["/", ".."]), Err(PathError::EscapesRoot));
+		assert_eq!(join(&["a/b", "../../.."]), Err(PathError::EscapesRoot));
+		assert_eq!(join(&[".."]), Err(PathError::EscapesRoot));
+	}
+
+	#[test]
+	fn unchanged_on_error() {
+		let mut buf = ConstStrBuf::<8>::new();
+		assert_eq!(buf.push_path_segment("/etc"), 4);
+		assert_eq!(buf.try_push_path_segment("x/../y"), Ok(4));
+		assert_eq!(buf, "/etc/y");
+		buf.truncate(4);
+		assert_eq!(
+			buf.try_push_path_segment("a/../../../x"),
+			Err(PathError::EscapesRoot)
+		);
+		assert_eq!(buf, "/etc");
+		assert_eq!(
+			buf.try_push_path_segment("..//configuration"),
+			Err(PathError::Overflow)
+		);
+		assert_eq!(buf, "/etc");
+	}
+
+	#[test]
+	fn concat_path() {
+		const APP: &str = "app";
+		const CONFIG: &str = concat_path!("/etc", APP, "config.toml");
+		const DATA: &str = concat_path!("/var//lib/", APP, "./tmp/../data/");
+		const RELATIVE: &str = concat_path!("assets", "..", "static");
+
+		assert_eq!(CONFIG, "/etc/app/config.toml");
+		assert_eq!(DATA, "/var/lib/app/data");
+		assert_eq!(RELATIVE, "static");
+	}
+}
@@ -0,0 +1,100 @@
+use cluConstData::const_semver;
+use cluConstData::semver::{Semver, SemverError};
+
+#[test]
+fn parse_semver() {
+	const VERSION: Semver = const_semver!("1.4.2-rc.1+sha.5114f85");
+	assert_eq!(VERSION.major, 1);
+	assert_eq!(VERSION.minor, 4);
+	assert_eq!(VERSION.patch, 2);
+	assert_eq!(VERSION.pre, "rc.1");
+	assert_eq!(VERSION.build, "sha.5114f85");
+
+	assert_eq!(Semver::parse("0.0.0"), Ok(Semver::new(0, 0, 0)));
+	assert_eq!(Semver::parse("1.0.0+007").map(|a| a.build), Ok("007"));
+	assert_eq!(Semver::parse("1.0.0-x-y.0a").map(|a| a.pre), Ok("x-y.0a"));
+
+	assert_eq!(Semver::parse(""), Err(SemverError::UnexpectedEnd));
+	assert_eq!(Semver::parse("1.0"), Err(SemverError::UnexpectedEnd));
+	assert_eq!(Semver::parse("1.0."), Err(SemverError::UnexpectedEnd));
+	assert_eq!(Semver::parse("v1.0.0"), Err(SemverError::InvalidChar));
+	assert_eq!(Semver::parse("1.0.0 "), Err(SemverError::InvalidChar));
+	assert_eq!(Semver::parse("1.0.0-a_b"), Err(SemverError::InvalidChar));
+	assert_eq!(Semver::parse("01.0.0"), Err(SemverError::LeadingZero));
+	assert_eq!(Semver::parse("1.0.0-01"), Err(SemverError::LeadingZero));
+	assert_eq!(
+		Semver::parse("1.0.0-a..b"),
+		Err(SemverError::EmptyIdentifier)
+	);
+	assert_eq!(Semver::parse("1.0.0+"), Err(SemverError::EmptyIdentifier));
+	assert_eq!(
+		Semver::parse("18446744073709551616.0.0"),
+		Err(SemverError::Overflow)
+	);
+
+	assert_eq!(VERSION.formatted_len(), VERSION.to_string().len());
+	assert_eq!(Semver::new(10, 0, 100).formatted_len(), "10.0.100".len());
+}
+
+#[test]
+fn semver_precedence() {
+	const ORDERED: [Semver; 8] = [
+		const_semver!("1.0.0-alpha"),
+		const_semver!("1.0.0-alpha.1"),
+		const_semver!("1.0.0-alpha.beta"),
+		const_semver!("1.0.0-beta"),
+		const_semver!("1.0.0-beta.2"),
+		const_semver!("1.0.0-beta.11"),
+		const_semver!("1.0.0-rc.1"),
+		const_semver!("1.0.0"),
+	];
+	const _: () = assert!(ORDERED[0].lt(&ORDERED[7]));
+
+	for (i, a) in ORDERED.iter().enumerate() {
+		for (j, b) in ORDERED.iter().enumerate() {
+			assert_eq!(a.cmp_precedence(b), i.cmp(&j), "{} vs {}", a, b);
+		}
+	}
+
+	let a = const_semver!("1.2.3+build.1");
+	let b = const_semver!("1.2.3+build.2");
+	assert!(a.eq_precedence(&b));
+	assert_ne!(a, b);
+	assert!(Semver::new(2, 0, 0).gt(&Semver::new(1, 99, 99)));
+	assert!(Semver::new(1, 10, 0).ge(&Semver::new(1, 9, 0)));
+	assert!(Semver::new(1, 0, 0).le(&Semver::new(1, 0, 0)));
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_semver_buf {
+	use cluConstData::buf::ConstStrBuf;
+	use cluConstData::const_semver;
+	use cluConstData::semver::Semver;
+
+	#[test]
+	fn push_semver() {
+		const VERSION: Semver = const_semver!("10.0.1-beta.2+exp.sha");
+		const BUF: ConstStrBuf<{ VERSION.formatted_len() }> = {
+			let mut buf = ConstStrBuf::new();
+			buf.push_semver(&VERSION);
+			buf
+		};
+		assert_eq!(BUF.as_str(), "10.0.1-beta.2+exp.sha");
+		assert_eq!(BUF.as_str(), VERSION.to_string());
+
+		let max = Semver::new(u64::MAX, 0, 0);
+		let mut buf = ConstStrBuf::<{ 20 + 4 }>::new();
+		assert_eq!(buf.try_push_semver(&max), Ok(24));
+		assert_eq!(buf.as_str(), "18446744073709551615.0.0");
+
+		let mut small = ConstStrBuf::<4>::new();
+		assert!(small.try_push_semver(&VERSION).is_err());
+		assert!(small.is_empty());
+	}
+
+	#[test]
+	fn crate_version() {
+		const VERSION: Semver = const_semver!(env!("CARGO_PKG_VERSION"));
+		assert_eq!(VERSION.to_string(), env!("CARGO_PKG_VERSION"));
+	}
+}
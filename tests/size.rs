@@ -31,4 +31,15 @@ mod test_size {
 			<char as ConstByteBufSize>::MAX_DECIMAL_LEN == (<char>::MAX as u32).to_string().len()
 		);
 	}
+
+	#[test]
+	fn test_leb128_sizes() {
+		use cluConstData::buf::size::ConstByteBufLeb128Size;
+
+		assert_eq!(u8::MAX_LEB128_LEN, 2);
+		assert_eq!(u16::MAX_LEB128_LEN, 3);
+		assert_eq!(u32::MAX_LEB128_LEN, 5);
+		assert_eq!(i64::MAX_LEB128_LEN, 10);
+		assert_eq!(u128::MAX_LEB128_LEN, 19);
+	}
 }
@@ -13,3 +13,17 @@ fn concat_str() {
 	assert!(core::str::from_utf8(HELLO_WORLD.as_bytes()).is_ok());
 	assert_eq!(HELLO_WORLD, "Hello World!");
 }
+
+#[test]
+fn concat_cstr() {
+	use cluConstData::concat_cstr;
+	use core::ffi::CStr;
+
+	const NAME: &str = "World";
+	const HELLO_WORLD: &CStr = concat_cstr!("Hello ", NAME, "!");
+	const SINGLE: &CStr = concat_cstr!("single");
+
+	assert_eq!(HELLO_WORLD, c"Hello World!");
+	assert_eq!(HELLO_WORLD.to_bytes_with_nul(), b"Hello World!\0");
+	assert_eq!(SINGLE, c"single");
+}
@@ -0,0 +1,61 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_time {
+	use cluConstData::buf::{ConstStrBuf, TimestampStyle};
+	use cluConstData::timestamp_str;
+
+	fn format(secs: i64, style: TimestampStyle) -> String {
+		let mut buf = ConstStrBuf::<64>::new();
+		let len = buf.push_unix_timestamp(secs, style);
+		assert_eq!(len, buf.len());
+		assert!(len <= style.max_len());
+
+		buf.to_string()
+	}
+
+	#[test]
+	fn unix_timestamp() {
+		use TimestampStyle::*;
+
+		assert_eq!(format(0, DateTime), "1970-01-01T00:00:00Z");
+		assert_eq!(format(0, Rfc2822), "Thu, 01 Jan 1970 00:00:00 +0000");
+		assert_eq!(format(-1, DateTime), "1969-12-31T23:59:59Z");
+		assert_eq!(format(951_782_400, Date), "2000-02-29");
+		assert_eq!(
+			format(1_709_164_800, Rfc2822),
+			"Thu, 29 Feb 2024 00:00:00 +0000"
+		);
+		assert_eq!(format(253_402_300_799, DateTime), "9999-12-31T23:59:59Z");
+		assert_eq!(format(253_402_300_800, Date), "+10000-01-01");
+		// documented as not RFC 2822 conforming
+		assert_eq!(
+			format(253_402_300_800, Rfc2822),
+			"Sat, 01 Jan +10000 00:00:00 +0000"
+		);
+		assert_eq!(format(-62_135_596_800, Date), "0001-01-01");
+		assert_eq!(format(-62_198_755_200, Date), "-0001-01-01");
+
+		for style in [Date, DateTime, Rfc2822] {
+			assert_eq!(format(i64::MAX, style).len(), style.max_len());
+			assert_eq!(format(i64::MIN, style).len(), style.max_len());
+		}
+
+		let mut small = ConstStrBuf::<10>::new();
+		assert!(small.try_push_unix_timestamp(0, DateTime).is_err());
+		assert!(small.is_empty());
+	}
+
+	#[test]
+	fn timestamp_macro() {
+		const EPOCH: i64 =
+			cluConstData::env_i64!("SOURCE_DATE_EPOCH_NOT_SET", default = 1_700_000_000);
+		const BUILT: &str = timestamp_str!(EPOCH);
+		const BUILT_DATE: &str = timestamp_str!(EPOCH, Date);
+
+		assert_eq!(BUILT, "2023-11-14T22:13:20Z");
+		assert_eq!(BUILT_DATE, "2023-11-14");
+		assert_eq!(
+			timestamp_str!(86_399, Rfc2822),
+			"Thu, 01 Jan 1970 23:59:59 +0000"
+		);
+	}
+}
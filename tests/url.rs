@@ -0,0 +1,87 @@
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_url {
+	use cluConstData::buf::{ConstStrBuf, UrlEncodeSet, UrlError, UrlParts};
+	use cluConstData::const_url;
+
+	#[test]
+	fn url_component() {
+		let mut buf = ConstStrBuf::<64>::new();
+		assert_eq!(
+			buf.push_url_component("a-b_c.d~e f", UrlEncodeSet::Unreserved),
+			13
+		);
+		assert_eq!(buf, "a-b_c.d~e%20f");
+
+		buf.clear();
+		buf.push_url_component("a/b:c@d=e", UrlEncodeSet::PathSegment);
+		assert_eq!(buf, "a%2Fb:c@d=e");
+
+		buf.clear();
+		buf.push_url_component("k=v&x+y?/#", UrlEncodeSet::QueryValue);
+		assert_eq!(buf, "k%3Dv%26x%2By?/%23");
+
+		buf.clear();
+		buf.push_url_component("ü", UrlEncodeSet::Unreserved);
+		assert_eq!(buf, "%C3%BC");
+		assert_eq!(UrlEncodeSet::Unreserved.encoded_len("ü"), 6);
+
+		let mut small = ConstStrBuf::<4>::new();
+		assert!(
+			small
+				.try_push_url_component("a b", UrlEncodeSet::Unreserved)
+				.is_err()
+		);
+		assert_eq!(small, "");
+	}
+
+	#[test]
+	fn url_parts() {
+		const PARTS: UrlParts<'static> = UrlParts {
+			scheme: "http",
+			host: "[::1]",
+			port: Some(80),
+			path: &[],
+			query: &[("a", "")],
+		};
+		assert_eq!(PARTS.formatted_len(), Ok("http://[::1]:80?a=".len()));
+
+		let mut buf = ConstStrBuf::<32>::new();
+		buf.push_url(&PARTS);
+		assert_eq!(buf, "http://[::1]:80?a=");
+
+		let invalid = |scheme, host| {
+			UrlParts {
+				scheme,
+				host,
+				..PARTS
+			}
+			.validate()
+		};
+		assert_eq!(invalid("", "a"), Err(UrlError::InvalidScheme));
+		assert_eq!(invalid("1http", "a"), Err(UrlError::InvalidScheme));
+		assert_eq!(invalid("git+ssh", "a"), Ok(()));
+		assert_eq!(invalid("http", ""), Err(UrlError::InvalidHost));
+		assert_eq!(invalid("http", "a/b"), Err(UrlError::InvalidHost));
+		assert_eq!(invalid("http", "[10.0.0.1]"), Err(UrlError::InvalidHost));
+
+		let mut small = ConstStrBuf::<8>::new();
+		assert_eq!(small.try_push_url(&PARTS), Err(UrlError::Overflow));
+		assert_eq!(small, "");
+	}
+
+	#[test]
+	fn const_url() {
+		const HOST: &str = "example.com";
+		const MINIMAL: &str = const_url!(scheme: "https", host: HOST);
+		const FULL: &str = const_url! {
+			scheme: "https",
+			host: HOST,
+			port: 8443,
+			path: ["api", "v1", "a b"],
+			query: [("q", "x&y"), ("n", "1")],
+		};
+
+		assert_eq!(MINIMAL, "https://example.com");
+		assert_eq!(FULL, "https://example.com:8443/api/v1/a%20b?q=x%26y&n=1");
+	}
+}
@@ -0,0 +1,89 @@
+use cluConstData::uuid::{UuidParseError, parse_uuid};
+use cluConstData::uuid_bytes;
+
+const EXAMPLE: [u8; 16] = [
+	0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00,
+];
+
+#[test]
+fn parse_uuids() {
+	const SERVICE: [u8; 16] = uuid_bytes!("550e8400-e29b-41d4-a716-446655440000");
+	assert_eq!(SERVICE, EXAMPLE);
+
+	for s in [
+		"550e8400e29b41d4a716446655440000",
+		"550E8400-E29B-41D4-A716-446655440000",
+		"{550e8400-e29b-41d4-a716-446655440000}",
+		"urn:uuid:550e8400-e29b-41d4-a716-446655440000",
+	] {
+		assert_eq!(parse_uuid(s), Ok(EXAMPLE), "{s}");
+	}
+
+	assert_eq!(parse_uuid(""), Err(UuidParseError::InvalidLength));
+	assert_eq!(
+		parse_uuid("550e8400-e29b-41d4-a716-44665544000"),
+		Err(UuidParseError::InvalidLength)
+	);
+	assert_eq!(
+		parse_uuid("550e8400-e29b-41d4-a716-44665544000x"),
+		Err(UuidParseError::InvalidChar)
+	);
+	assert_eq!(
+		parse_uuid("550e8400e-29b-41d4-a716-446655440000"),
+		Err(UuidParseError::InvalidGroup)
+	);
+	assert_eq!(
+		parse_uuid("(550e8400-e29b-41d4-a716-446655440000)"),
+		Err(UuidParseError::InvalidGroup)
+	);
+	assert_eq!(
+		parse_uuid("urn:uid:550e8400-e29b-41d4-a716-4466554400000"),
+		Err(UuidParseError::InvalidGroup)
+	);
+}
+
+#[cfg(any(test, feature = "const_buf"))]
+mod test_const_uuid {
+	use super::EXAMPLE;
+	use cluConstData::buf::{ConstByteBuf, ConstStrBuf, HexCase, UuidStyle};
+	use cluConstData::uuid::parse_uuid;
+
+	#[test]
+	fn push_uuid() {
+		for (style, case, expected) in [
+			(
+				UuidStyle::Hyphenated,
+				HexCase::Lower,
+				"550e8400-e29b-41d4-a716-446655440000",
+			),
+			(
+				UuidStyle::Simple,
+				HexCase::Upper,
+				"550E8400E29B41D4A716446655440000",
+			),
+			(
+				UuidStyle::Braced,
+				HexCase::Lower,
+				"{550e8400-e29b-41d4-a716-446655440000}",
+			),
+			(
+				UuidStyle::Urn,
+				HexCase::Lower,
+				"urn:uuid:550e8400-e29b-41d4-a716-446655440000",
+			),
+		] {
+			let mut buf = ConstStrBuf::<64>::new();
+			assert_eq!(buf.push_uuid(&EXAMPLE, style, case), style.formatted_len());
+			assert_eq!(buf, expected);
+			assert_eq!(parse_uuid(&buf), Ok(EXAMPLE));
+		}
+
+		let mut small = ConstByteBuf::<35>::new();
+		assert!(
+			small
+				.try_push_uuid(&EXAMPLE, UuidStyle::Hyphenated, HexCase::Lower)
+				.is_err()
+		);
+		assert!(small.is_empty());
+	}
+}